@@ -0,0 +1,570 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{mpsc, Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::entry::LogEntry;
+use crate::sink::LoggerSink;
+
+// ---- //
+// Type //
+// ---- //
+
+/// Formate les logs émis par le thread d'écriture lui-même (voir
+/// [AsyncShared::report_dropped]), comme ceux du logger.
+pub(crate) type AsyncFormatFn = Box<dyn Fn(&LogEntry) -> Option<String> + Send>;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Les threads d'écriture démarrés, arrêtés par [shutdown].
+static WORKERS: Mutex<Vec<Weak<AsyncShared>>> = Mutex::new(Vec::new());
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Paramètres du mode asynchrone d'un logger.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(Eq, PartialEq)]
+pub struct LoggerAsync
+{
+	capacity: usize,
+	backpressure: LoggerBackpressure,
+	report_interval: Duration,
+}
+
+/// Destination asynchrone: les logs sont poussés dans une file bornée et
+/// écrits dans la destination par un thread dédié.
+pub(crate) struct AsyncSink
+{
+	shared: Arc<AsyncShared>,
+}
+
+/// File partagée entre les loggers et le thread d'écriture.
+struct AsyncShared
+{
+	options: LoggerAsync,
+	queue: Mutex<AsyncQueue>,
+	not_empty: Condvar,
+	not_full: Condvar,
+	worker: Mutex<Option<thread::JoinHandle<Box<dyn LoggerSink>>>>,
+	/// La destination, rendue par le thread d'écriture une fois arrêté: les
+	/// logs suivants y sont écrits directement.
+	detached: Mutex<Option<Box<dyn LoggerSink>>>,
+}
+
+#[derive(Default)]
+struct AsyncQueue
+{
+	messages: VecDeque<AsyncMessage>,
+	dropped: u64,
+	closed: bool,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Comportement du mode asynchrone lorsque la file est pleine.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(Eq, PartialEq)]
+pub enum LoggerBackpressure
+{
+	/// Attend qu'une place se libère dans la file.
+	#[default]
+	Block,
+	/// Ignore le nouveau log.
+	DropNewest,
+	/// Retire le plus ancien log de la file pour faire de la place.
+	DropOldest,
+	/// Ignore le nouveau log, sauf s'il s'agit d'une erreur: le plus ancien
+	/// log (hors erreurs) de la file est alors retiré, ou, à défaut, une
+	/// place est attendue.
+	KeepErrors,
+}
+
+enum AsyncMessage
+{
+	Log(Box<LogEntry>, String),
+	Flush(mpsc::Sender<()>),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerAsync
+{
+	/// File de `capacity` logs, bloquante lorsqu'elle est pleine. Une
+	/// capacité nulle est ramenée à 1.
+	pub const fn new(capacity: usize) -> Self
+	{
+		Self {
+			capacity: if capacity == 0 { 1 } else { capacity },
+			backpressure: LoggerBackpressure::Block,
+			report_interval: Duration::from_secs(10),
+		}
+	}
+
+	/// Comportement lorsque la file est pleine.
+	pub fn with_backpressure(mut self, backpressure: impl Into<LoggerBackpressure>) -> Self
+	{
+		self.backpressure = backpressure.into();
+		self
+	}
+
+	/// Intervalle minimal entre deux logs d'avertissement signalant le
+	/// nombre de logs ignorés lorsque la file était pleine.
+	pub fn with_report_interval(mut self, interval: Duration) -> Self
+	{
+		self.report_interval = interval;
+		self
+	}
+}
+
+impl AsyncSink
+{
+	/// Rend la destination asynchrone si des paramètres sont donnés.
+	pub(crate) fn wrap(
+		sink: impl LoggerSink + 'static,
+		options: Option<LoggerAsync>,
+		format: impl Fn(&LogEntry) -> Option<String> + Send + 'static,
+	) -> Box<dyn LoggerSink>
+	{
+		match options {
+			| Some(options) => Box::new(Self::spawn(Box::new(sink), options, Box::new(format))),
+			| None => Box::new(sink),
+		}
+	}
+
+	/// Démarre le thread d'écriture de la destination `sink`.
+	fn spawn(sink: Box<dyn LoggerSink>, options: LoggerAsync, format: AsyncFormatFn) -> Self
+	{
+		let shared = Arc::new(AsyncShared {
+			options,
+			queue: Default::default(),
+			not_empty: Default::default(),
+			not_full: Default::default(),
+			worker: Default::default(),
+			detached: Default::default(),
+		});
+
+		let worker = thread::Builder::new()
+			.name("lexa-logger-writer".to_owned())
+			.spawn({
+				let shared = Arc::clone(&shared);
+				move || shared.run(sink, format)
+			})
+			.expect("Le démarrage du thread d'écriture des logs");
+
+		shared.worker.lock().expect("async worker guard").replace(worker);
+
+		let mut workers = WORKERS.lock().unwrap_or_else(|err| err.into_inner());
+		workers.retain(|worker| worker.strong_count() > 0);
+		workers.push(Arc::downgrade(&shared));
+
+		Self { shared }
+	}
+}
+
+impl AsyncShared
+{
+	fn lock(&self) -> MutexGuard<'_, AsyncQueue>
+	{
+		self.queue.lock().unwrap_or_else(|err| err.into_inner())
+	}
+
+	/// Pousse un log dans la file, selon le comportement choisi lorsque la
+	/// file est pleine.
+	fn push(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let mut queue = self.lock();
+
+		loop {
+			if queue.closed {
+				drop(queue);
+				return self.detached(|sink| sink.write(entry, text));
+			}
+
+			if queue.messages.len() < self.options.capacity {
+				break;
+			}
+
+			let evicted = match self.options.backpressure {
+				| LoggerBackpressure::Block => None,
+				| LoggerBackpressure::DropNewest => {
+					queue.dropped += 1;
+					return Ok(());
+				}
+				| LoggerBackpressure::DropOldest => queue.evict(|_| true),
+				| LoggerBackpressure::KeepErrors if entry.level != log::Level::Error => {
+					queue.dropped += 1;
+					return Ok(());
+				}
+				| LoggerBackpressure::KeepErrors => queue.evict(|level| level != log::Level::Error),
+			};
+
+			if evicted.is_some() {
+				queue.dropped += 1;
+				break;
+			}
+
+			queue = self.not_full.wait(queue).unwrap_or_else(|err| err.into_inner());
+		}
+
+		queue.messages.push_back(AsyncMessage::Log(Box::new(entry.clone()), text.to_owned()));
+		self.not_empty.notify_one();
+
+		Ok(())
+	}
+
+	/// Attend que les logs en file soient écrits, puis que la destination
+	/// soit vidée.
+	fn flush(&self) -> io::Result<()>
+	{
+		let (done, wait) = mpsc::channel();
+
+		{
+			let mut queue = self.lock();
+			if queue.closed {
+				drop(queue);
+				return self.detached(|sink| sink.flush());
+			}
+			queue.messages.push_back(AsyncMessage::Flush(done));
+			self.not_empty.notify_one();
+		}
+
+		wait.recv().map_err(|_| io::ErrorKind::BrokenPipe.into())
+	}
+
+	/// Utilise la destination rendue par le thread d'écriture arrêté.
+	fn detached(&self, f: impl FnOnce(&dyn LoggerSink) -> io::Result<()>) -> io::Result<()>
+	{
+		match self.detached.lock().expect("async detached guard").as_deref() {
+			| Some(sink) => f(sink),
+			| None => Err(io::ErrorKind::BrokenPipe.into()),
+		}
+	}
+
+	/// Ferme la file et attend que le thread d'écriture ait écrit les logs
+	/// restants. La destination est ensuite utilisée directement.
+	fn shutdown(&self)
+	{
+		self.lock().closed = true;
+		self.not_empty.notify_all();
+		self.not_full.notify_all();
+
+		let worker = self.worker.lock().expect("async worker guard").take();
+		if let Some(Ok(sink)) = worker.map(thread::JoinHandle::join) {
+			self.detached.lock().expect("async detached guard").replace(sink);
+		}
+	}
+
+	/// Boucle du thread d'écriture.
+	fn run(&self, sink: Box<dyn LoggerSink>, format: AsyncFormatFn) -> Box<dyn LoggerSink>
+	{
+		let mut last_report = Instant::now();
+
+		loop {
+			let (message, closed) = {
+				let mut queue = self.lock();
+
+				if queue.messages.is_empty() && !queue.closed {
+					queue = self
+						.not_empty
+						.wait_timeout(queue, self.options.report_interval)
+						.map(|(queue, _)| queue)
+						.unwrap_or_else(|err| err.into_inner().0);
+				}
+
+				let message = queue.messages.pop_front();
+				if message.is_some() {
+					self.not_full.notify_one();
+				}
+
+				(message, queue.closed)
+			};
+
+			match message {
+				| Some(AsyncMessage::Log(entry, text)) => {
+					_ = sink.write(&entry, &text);
+				}
+				| Some(AsyncMessage::Flush(done)) => {
+					_ = sink.flush();
+					_ = done.send(());
+				}
+				| None if closed => break,
+				| None => {}
+			}
+
+			if last_report.elapsed() >= self.options.report_interval {
+				last_report = Instant::now();
+				self.report_dropped(sink.as_ref(), &format);
+			}
+		}
+
+		_ = sink.flush();
+		sink
+	}
+
+	/// Signale, par un log d'avertissement, le nombre de logs ignorés depuis
+	/// le dernier signalement.
+	///
+	/// Le log est écrit directement dans la destination: il ne passe ni par
+	/// la file, où il pourrait à son tour être ignoré, ni par les autres
+	/// loggers.
+	fn report_dropped(&self, sink: &dyn LoggerSink, format: &AsyncFormatFn)
+	{
+		let dropped = std::mem::take(&mut self.lock().dropped);
+		if dropped == 0 {
+			return;
+		}
+
+		let mut entry = LogEntry::new(
+			log::Level::Warn,
+			module_path!(),
+			format!("{dropped} log(s) ignoré(s): la file du mode asynchrone était pleine."),
+		);
+		entry.module_path.replace(module_path!().to_owned());
+		entry.file.replace(file!().to_owned());
+		entry.line.replace(line!());
+
+		if let Some(text) = format(&entry) {
+			_ = sink.write(&entry, &text);
+		}
+	}
+}
+
+impl AsyncQueue
+{
+	/// Retire le plus ancien log de la file dont le niveau satisfait le
+	/// prédicat. Les demandes de vidage ne sont jamais retirées.
+	fn evict(&mut self, predicate: impl Fn(log::Level) -> bool) -> Option<AsyncMessage>
+	{
+		let idx = self
+			.messages
+			.iter()
+			.position(|message| matches!(message, AsyncMessage::Log(entry, _) if predicate(entry.level)))?;
+
+		self.messages.remove(idx)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<usize> for LoggerAsync
+{
+	fn from(capacity: usize) -> Self
+	{
+		Self::new(capacity)
+	}
+}
+
+impl LoggerSink for AsyncSink
+{
+	/// Pousse le log dans la file.
+	fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+	{
+		self.shared.push(entry, text)
+	}
+
+	/// Attend que les logs en file soient écrits, puis vide la destination.
+	fn flush(&self) -> io::Result<()>
+	{
+		self.shared.flush()
+	}
+}
+
+impl Drop for AsyncSink
+{
+	/// Ferme la file et attend que le thread ait écrit les logs restants.
+	fn drop(&mut self)
+	{
+		self.shared.shutdown();
+	}
+}
+
+/// Arrête tous les threads d'écriture, après qu'ils aient écrit les logs
+/// restants.
+pub(crate) fn shutdown()
+{
+	let workers = std::mem::take(&mut *WORKERS.lock().unwrap_or_else(|err| err.into_inner()));
+
+	for shared in workers.iter().filter_map(Weak::upgrade) {
+		shared.shutdown();
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io;
+	use std::sync::{mpsc, Arc, Mutex};
+	use std::time::{Duration, Instant};
+
+	use super::{AsyncSink, LoggerAsync, LoggerBackpressure};
+	use crate::entry::LogEntry;
+	use crate::sink::LoggerSink;
+
+	/// Destination qui signale chaque écriture, puis attend que la porte
+	/// soit ouverte.
+	#[derive(Clone)]
+	struct GateSink
+	{
+		gate: Arc<Mutex<()>>,
+		entered: Arc<Mutex<mpsc::Sender<()>>>,
+		written: Arc<Mutex<Vec<(log::Level, String)>>>,
+	}
+
+	impl LoggerSink for GateSink
+	{
+		fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+		{
+			_ = self.entered.lock().unwrap().send(());
+			let _gate = self.gate.lock().unwrap();
+			self.written.lock().unwrap().push((entry.level, text.to_owned()));
+			Ok(())
+		}
+	}
+
+	/// Démarre une destination asynchrone dont le thread d'écriture est
+	/// bloqué sur le premier log, puis y pousse `entries`.
+	fn blocked(options: LoggerAsync, entries: &[(log::Level, &str)]) -> (AsyncSink, GateSink)
+	{
+		let (entered, entered_rx) = mpsc::channel();
+		let sink = GateSink {
+			gate: Default::default(),
+			entered: Arc::new(Mutex::new(entered)),
+			written: Default::default(),
+		};
+
+		let gate = sink.gate.lock().unwrap();
+		let async_sink = AsyncSink::spawn(
+			Box::new(sink.clone()),
+			options,
+			Box::new(|entry: &LogEntry| Some(entry.message.clone())),
+		);
+
+		for (idx, (level, message)) in entries.iter().enumerate() {
+			async_sink.write(&LogEntry::new(*level, "test", message), message).unwrap();
+
+			if idx == 0 {
+				entered_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+			}
+		}
+
+		drop(gate);
+		(async_sink, sink)
+	}
+
+	fn messages(sink: &GateSink) -> Vec<String>
+	{
+		sink.written.lock().unwrap().iter().map(|(_, text)| text.clone()).collect()
+	}
+
+	#[test]
+	fn zero_capacity_is_clamped()
+	{
+		assert_eq!(LoggerAsync::new(0), LoggerAsync::new(1));
+		assert_eq!(LoggerAsync::from(0), LoggerAsync::new(1));
+
+		let (done, wait) = mpsc::channel();
+		std::thread::spawn(move || {
+			let (async_sink, sink) = blocked(LoggerAsync::new(0), &[(log::Level::Info, "1"), (log::Level::Info, "2")]);
+			async_sink.flush().unwrap();
+			_ = done.send(messages(&sink));
+		});
+
+		assert_eq!(wait.recv_timeout(Duration::from_secs(5)).unwrap(), ["1", "2"]);
+	}
+
+	#[test]
+	fn drop_newest_ignores_the_new_log()
+	{
+		let options = LoggerAsync::new(1).with_backpressure(LoggerBackpressure::DropNewest);
+		let (async_sink, sink) = blocked(options, &[
+			(log::Level::Info, "1"),
+			(log::Level::Info, "2"),
+			(log::Level::Info, "3"),
+		]);
+		async_sink.flush().unwrap();
+
+		assert_eq!(messages(&sink), ["1", "2"]);
+		assert_eq!(async_sink.shared.lock().dropped, 1);
+	}
+
+	#[test]
+	fn drop_oldest_evicts_the_queued_log()
+	{
+		let options = LoggerAsync::new(1).with_backpressure(LoggerBackpressure::DropOldest);
+		let (async_sink, sink) = blocked(options, &[
+			(log::Level::Info, "1"),
+			(log::Level::Info, "2"),
+			(log::Level::Info, "3"),
+		]);
+		async_sink.flush().unwrap();
+
+		assert_eq!(messages(&sink), ["1", "3"]);
+	}
+
+	#[test]
+	fn keep_errors_evicts_logs_for_errors_only()
+	{
+		let options = LoggerAsync::new(1).with_backpressure(LoggerBackpressure::KeepErrors);
+		let (async_sink, sink) = blocked(options, &[
+			(log::Level::Info, "1"),
+			(log::Level::Info, "2"),
+			(log::Level::Info, "3"),
+			(log::Level::Error, "4"),
+		]);
+		async_sink.flush().unwrap();
+
+		assert_eq!(messages(&sink), ["1", "4"]);
+		assert_eq!(async_sink.shared.lock().dropped, 2);
+	}
+
+	#[test]
+	fn dropped_logs_are_reported_to_the_sink()
+	{
+		let options = LoggerAsync::new(1)
+			.with_backpressure(LoggerBackpressure::DropNewest)
+			.with_report_interval(Duration::from_millis(10));
+		let (async_sink, sink) = blocked(options, &[
+			(log::Level::Info, "1"),
+			(log::Level::Info, "2"),
+			(log::Level::Info, "3"),
+			(log::Level::Info, "4"),
+		]);
+
+		let deadline = Instant::now() + Duration::from_secs(5);
+		while sink.written.lock().unwrap().len() < 3 && Instant::now() < deadline {
+			std::thread::sleep(Duration::from_millis(5));
+		}
+
+		// NOTE: l'avertissement peut être écrit avant ou après le log "2".
+		let written = sink.written.lock().unwrap().clone();
+		let warnings: Vec<_> = written.iter().filter(|(level, _)| *level == log::Level::Warn).collect();
+		assert_eq!(written.len(), 3);
+		assert_eq!(warnings.len(), 1);
+		assert!(warnings[0].1.starts_with("2 log(s) ignoré(s)"));
+		assert_eq!(async_sink.shared.lock().dropped, 0);
+	}
+}
@@ -0,0 +1,208 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::sync::Arc;
+
+use super::extension::LoggerBackendBuilderExtension;
+use crate::asynchronous::AsyncSink;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+use crate::LoggerAsync;
+use crate::processor::LoggerProcessors;
+use crate::sink::LoggerSink;
+use crate::{LoggerBackend, LoggerBuilder, LoggerFilter, LoggerFormatter, LoggerProcessor, LoggerTemplateFormat};
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Destination des logs d'un [LoggerBackendBuilder]: la sortie standard, un
+/// fichier, un écrivain ou une destination utilisateur.
+pub trait LoggerBackendDestination
+{
+	/// Le logger construit autour de cette destination.
+	type Logger;
+
+	/// Construit le logger. `backend` construit le [LoggerBackend] (niveau,
+	/// filtres, format, ...) autour de la destination des logs.
+	fn build(self, backend: impl FnOnce(Box<dyn LoggerSink>) -> LoggerBackend) -> Self::Logger;
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Paramètres communs à tous les loggers, et destination `D` de leurs logs.
+pub struct LoggerBackendBuilder<D = Box<dyn LoggerSink>>
+{
+	pub(crate) destination: D,
+	colorized: bool,
+	timestamp: bool,
+	#[cfg(not(feature = "tracing"))]
+	level: Option<log::LevelFilter>,
+	#[cfg(feature = "tracing")]
+	level: Option<tracing::level_filters::LevelFilter>,
+	formatter: Option<Box<dyn LoggerFormatter>>,
+	filter: LoggerFilter,
+	processors: LoggerProcessors,
+	async_options: Option<LoggerAsync>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerBackendBuilder
+{
+	/// Aucun paramètre n'est appliqué par défaut, sauf le format des logs.
+	pub fn new(sink: impl LoggerSink + 'static) -> Self
+	{
+		Self::with_destination(Box::new(sink))
+	}
+}
+
+impl<D> LoggerBackendBuilder<D>
+{
+	/// Aucun paramètre n'est appliqué par défaut, sauf le format des logs.
+	pub(crate) fn with_destination(destination: D) -> Self
+	{
+		Self {
+			destination,
+			colorized: Default::default(),
+			timestamp: Default::default(),
+			level: Default::default(),
+			formatter: Default::default(),
+			filter: Default::default(),
+			processors: Default::default(),
+			async_options: Default::default(),
+		}
+	}
+
+	/// Les logs sont écrits dans la destination par un thread dédié: le log est
+	/// seulement formaté, puis poussé dans une file bornée (voir
+	/// [LoggerAsync]).
+	pub fn with_async(mut self, options: impl Into<LoggerAsync>) -> Self
+	{
+		self.async_options.replace(options.into());
+		self
+	}
+
+	/// Ajoute un processeur, appliqué à chaque log avant son formatage.
+	/// Les processeurs sont appliqués dans leur ordre d'ajout.
+	pub fn with_processor(mut self, processor: impl LoggerProcessor + 'static) -> Self
+	{
+		self.processors.push(processor);
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<D> LoggerBuilder<D::Logger> for LoggerBackendBuilder<D>
+where
+	D: LoggerBackendDestination,
+{
+	fn filter<F>(mut self, predicate: F, dependency: impl ToString) -> Self
+	where
+		F: 'static,
+		F: Send + Sync,
+		F: Fn(&log::Metadata) -> bool,
+	{
+		self.filter.push_callback(predicate);
+		self.filter.add_dependency(dependency);
+		self
+	}
+
+	fn with_color(mut self, colorized: impl Into<bool>) -> Self
+	{
+		self.colorized = colorized.into();
+		self
+	}
+
+	fn with_format(mut self, format: impl LoggerFormatter + 'static) -> Self
+	{
+		self.formatter.replace(Box::new(format));
+		self
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	fn with_level(mut self, level: impl Into<log::LevelFilter>) -> Self
+	{
+		self.level.replace(level.into());
+		self
+	}
+
+	#[cfg(feature = "tracing")]
+	fn with_level(mut self, level: impl Into<tracing::level_filters::LevelFilter>) -> Self
+	{
+		self.level.replace(level.into());
+		self
+	}
+
+	fn with_timestamp(mut self, b: impl Into<bool>) -> Self
+	{
+		self.timestamp = b.into();
+		self
+	}
+
+	fn build(self) -> D::Logger
+	{
+		let Self {
+			destination,
+			colorized,
+			timestamp,
+			level,
+			formatter,
+			filter,
+			processors,
+			async_options,
+		} = self;
+
+		let formatter: Arc<dyn LoggerFormatter> = match formatter {
+			| Some(formatter) => formatter.into(),
+			| None => Arc::new(LoggerTemplateFormat::default()),
+		};
+
+		// NOTE: les avertissements du mode asynchrone sont formatés comme les
+		// autres logs.
+		let format = {
+			let formatter = Arc::clone(&formatter);
+			move |entry: &LogEntry| Echo::format(entry, colorized, timestamp, formatter.as_ref())
+		};
+
+		destination.build(|sink| LoggerBackend {
+			colorized,
+			filter,
+			processors,
+			formatter,
+			#[cfg(not(feature = "tracing"))]
+			level: level.unwrap_or(log::LevelFilter::Off),
+			#[cfg(feature = "tracing")]
+			level: level.unwrap_or(tracing_subscriber::filter::LevelFilter::OFF),
+			timestamp,
+			sink: AsyncSink::wrap(sink, async_options, format),
+		})
+	}
+}
+
+/// Destination fournie par l'utilisateur (voir [LoggerSink]).
+impl LoggerBackendDestination for Box<dyn LoggerSink>
+{
+	type Logger = LoggerBackend;
+
+	fn build(self, backend: impl FnOnce(Box<dyn LoggerSink>) -> LoggerBackend) -> Self::Logger
+	{
+		backend(self)
+	}
+}
+
+impl LoggerBackendBuilderExtension for LoggerBackendBuilder {}
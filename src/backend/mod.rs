@@ -0,0 +1,124 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod builder;
+mod extension;
+
+use std::sync::Arc;
+
+pub use self::builder::{LoggerBackendBuilder, LoggerBackendDestination};
+pub use self::extension::LoggerBackendBuilderExtension;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+use crate::format::LoggerFormatter;
+use crate::processor::LoggerProcessors;
+use crate::sink::LoggerSink;
+use crate::LoggerFilter;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Logger écrivant dans une destination (voir [LoggerSink]).
+///
+/// Les autres loggers (stdout, fichier, écrivain) sont construits sur celui-ci,
+/// seule leur destination diffère.
+pub struct LoggerBackend
+{
+	pub(crate) colorized: bool,
+	pub(crate) timestamp: bool,
+	#[cfg(not(feature = "tracing"))]
+	pub(crate) level: log::LevelFilter,
+	#[cfg(feature = "tracing")]
+	pub(crate) level: tracing::level_filters::LevelFilter,
+	pub(crate) formatter: Arc<dyn LoggerFormatter>,
+	pub(crate) filter: LoggerFilter,
+	pub(crate) processors: LoggerProcessors,
+	pub(crate) sink: Box<dyn LoggerSink>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerBackend
+{
+	pub fn builder(sink: impl LoggerSink + 'static) -> LoggerBackendBuilder
+	{
+		LoggerBackendBuilder::new(sink)
+	}
+}
+
+impl LoggerBackend
+{
+	#[cfg(not(feature = "tracing"))]
+	pub fn level(&self) -> log::LevelFilter
+	{
+		self.level
+	}
+
+	#[cfg(feature = "tracing")]
+	pub fn level(&self) -> tracing::level_filters::LevelFilter
+	{
+		self.level
+	}
+
+	/// Applique les processeurs au log, puis le formate et l'écrit.
+	pub(crate) fn write_entry(&self, entry: LogEntry)
+	{
+		let Some(entry) = self.processors.process(entry) else {
+			return;
+		};
+
+		let Some(text) = Echo::format(&entry, self.colorized, self.timestamp, self.formatter.as_ref()) else {
+			return;
+		};
+
+		_ = self.sink.write(&entry, &text);
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl log::Log for LoggerBackend
+{
+	/// On ne veut pas écrire les logs si le niveau est à
+	/// [log::LevelFilter::Off], ni ceux d'un niveau supérieur à celui du
+	/// logger.
+	///
+	/// Des conditions utilisateurs peuvent être utilisées pour filtrer les
+	/// logs.
+	fn enabled(&self, metadata: &log::Metadata) -> bool
+	{
+		#[cfg(not(feature = "tracing"))]
+		if metadata.level() > self.level {
+			return false;
+		}
+
+		metadata.level() != log::LevelFilter::Off && self.filter.is_enabled(metadata)
+	}
+
+	/// Écrit le log dans la destination.
+	fn log(&self, record: &log::Record)
+	{
+		if !self.enabled(record.metadata()) {
+			return;
+		}
+
+		self.write_entry(LogEntry::from(record));
+	}
+
+	fn flush(&self)
+	{
+		_ = self.sink.flush();
+	}
+}
@@ -8,13 +8,11 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
-use crate::echo::Echo;
 
 // ---- //
 // Type //
 // ---- //
 
-pub(crate) type LoggerFormatFn = fn(&std::fmt::Arguments, &log::Record, &mut Echo) -> String;
 pub(crate) type LoggerFilterCallback = dyn Fn(&log::Metadata) -> bool + Send + Sync;
 
 // --------- //
@@ -34,8 +32,9 @@ pub trait LoggerBuilder<T>
 	/// contrôlées par notre système.
 	fn with_color(self, colorized: impl Into<bool>) -> Self;
 
-	/// Le format du log.
-	fn with_format(self, format: LoggerFormatFn) -> Self;
+	/// Le format du log : une structure de format (voir
+	/// [crate::LoggerTemplateFormat]) ou une closure.
+	fn with_format(self, format: impl crate::LoggerFormatter + 'static) -> Self;
 
 	/// Le niveau de log.
 	#[cfg(not(feature = "tracing"))]
@@ -74,6 +73,134 @@ impl Logger
 			.with_timestamp(true)
 	}
 
+	/// Monteur de structure d'un logger de type fichier.
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_timestamp()]
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	pub fn file(path: impl Into<std::path::PathBuf>) -> crate::file::LoggerFileBuilder
+	{
+		crate::file::LoggerFile::builder(path)
+			.with_color(false)
+			.with_timestamp(true)
+	}
+
+	/// Monteur de structure d'un logger écrivant dans n'importe quel
+	/// [std::io::Write] (socket, pipe, `Vec<u8>`, ...).
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_timestamp()]
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	pub fn writer(writer: impl std::io::Write + Send + 'static) -> crate::writer::LoggerWriterBuilder
+	{
+		crate::writer::LoggerWriter::builder(writer)
+			.with_color(false)
+			.with_timestamp(true)
+	}
+
+	/// Monteur de structure d'un logger écrivant dans une destination
+	/// fournie par l'utilisateur (voir [crate::LoggerSink]).
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_timestamp()]
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	pub fn sink(sink: impl crate::LoggerSink + 'static) -> crate::backend::LoggerBackendBuilder
+	{
+		crate::backend::LoggerBackend::builder(sink)
+			.with_color(false)
+			.with_timestamp(true)
+	}
+
+	/// Monteur de structure d'un logger envoyant les logs à un serveur
+	/// Graylog, au format GELF.
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_format()] : [crate::LoggerGelfFormat]
+	///    2. [LoggerBuilder::with_timestamp()]
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	pub fn gelf(transport: crate::LoggerGelfTransport) -> crate::backend::LoggerBackendBuilder
+	{
+		Self::sink(transport).with_format(crate::LoggerGelfFormat::default())
+	}
+
+	/// Monteur de structure d'un logger envoyant les logs à un collecteur
+	/// OpenTelemetry, au format OTLP/JSON.
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_format()] : [crate::LoggerOtlpFormat]
+	///    2. [LoggerBuilder::with_timestamp()]
+	///    3. [crate::backend::LoggerBackendBuilder::with_async()] : une file de 1024
+	///       logs, pour ne pas attendre la réponse du collecteur à chaque log
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	///
+	/// NOTE: pour des envois synchrones, utiliser [Logger::sink()] avec le
+	/// format [crate::LoggerOtlpFormat].
+	pub fn otlp(transport: crate::LoggerOtlpTransport) -> crate::backend::LoggerBackendBuilder
+	{
+		Self::sink(transport)
+			.with_format(crate::LoggerOtlpFormat::default())
+			.with_async(crate::LoggerAsync::new(1024))
+	}
+
+	/// Monteur de structure d'un logger envoyant les logs à un démon syslog.
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_format()] : le message seul, sans code ANSI,
+	///       l'en-tête syslog étant construit par [crate::LoggerSyslogTransport]
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	///    2. [LoggerBuilder::with_timestamp()]
+	pub fn syslog(transport: crate::LoggerSyslogTransport) -> crate::backend::LoggerBackendBuilder
+	{
+		Self::sink(transport)
+			.with_format(crate::format::message_format)
+			.with_timestamp(false)
+	}
+
+	/// Monteur de structure d'un logger envoyant les logs à systemd-journald.
+	///
+	/// Paramètres activés:
+	///    1. [LoggerBuilder::with_format()] : le message seul, sans code ANSI,
+	///       les autres données du log étant des champs du journal (voir
+	///       [crate::LoggerJournaldTransport])
+	///
+	/// Paramètres désactivés:
+	///    1. [LoggerBuilder::with_color()]
+	///    2. [LoggerBuilder::with_timestamp()]
+	#[cfg(unix)]
+	pub fn journald(transport: crate::LoggerJournaldTransport) -> crate::backend::LoggerBackendBuilder
+	{
+		Self::sink(transport)
+			.with_format(crate::format::message_format)
+			.with_timestamp(false)
+	}
+
+	/// Monteur de structure d'un logger distribuant chaque log à plusieurs
+	/// loggers, par exemple un terminal en couleurs et un fichier en texte
+	/// brut.
+	///
+	/// NOTE: indisponible avec la fonctionnalité `tracing`. Avec `tracing`,
+	/// les logs sont formatés par `tracing_subscriber`, puis écrits dans une
+	/// seule destination: le niveau, le format et les routes propres à
+	/// chaque logger du logger multi ne peuvent pas s'y appliquer. Pour
+	/// plusieurs destinations, combiner des couches `tracing_subscriber`.
+	#[cfg(not(feature = "tracing"))]
+	pub fn multi() -> crate::multi::LoggerMultiBuilder
+	{
+		crate::multi::LoggerMulti::builder()
+	}
+
 	// NOTE: Ajouter d'autres types de builder avec des paramètres par défaut
 	// ici...
 }
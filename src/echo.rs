@@ -8,35 +8,148 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use std::borrow::Cow;
+
+use console::style;
+
+use crate::entry::LogEntry;
+use crate::format::LoggerFormatter;
 use crate::layout;
 
 // --------- //
 // Structure //
 // --------- //
 
+/// Contexte donné aux formats (voir [crate::LoggerFormatter]).
+///
+/// Les informations sont déjà préparées selon les paramètres du logger : le
+/// niveau et le délimiteur sont stylisés lorsque les couleurs sont activées,
+/// et le timestamp n'est présent que lorsqu'il est activé.
 pub struct Echo<'a>
 {
-	pub(crate) colorized: bool,
-	pub(super) time: Option<chrono::DateTime<chrono::Local>>,
-	pub(super) delimiter: String,
-	pub(super) level: String,
-	pub(super) record_level: log::Level,
-	pub(super) table: &'a mut layout::GridLayout<'a>,
+	colorized: bool,
+	time: Option<chrono::DateTime<chrono::Local>>,
+	delimiter: String,
+	level: String,
+	table: &'a mut layout::GridLayout<'a>,
 }
 
 // -------------- //
 // Implémentation //
 // -------------- //
 
+impl<'a> Echo<'a>
+{
+	/// Les couleurs sont-elles activées ?
+	pub fn is_colorized(&self) -> bool
+	{
+		self.colorized
+	}
+
+	/// La date du log, lorsque le timestamp est activé.
+	pub fn time(&self) -> Option<chrono::DateTime<chrono::Local>>
+	{
+		self.time
+	}
+
+	/// Le délimiteur des colonnes, `|`, en rouge lorsque les couleurs sont
+	/// activées.
+	pub fn delimiter(&self) -> &str
+	{
+		&self.delimiter
+	}
+
+	/// Le niveau du log, aligné sur 5 caractères et coloré lorsque les
+	/// couleurs sont activées.
+	pub fn level(&self) -> &str
+	{
+		&self.level
+	}
+
+	/// La grille, sans bordure et d'une largeur maximale de 120 caractères,
+	/// dans laquelle le format peut ajouter ses lignes avant de la rendre.
+	pub fn table(&mut self) -> &mut layout::GridLayout<'a>
+	{
+		self.table
+	}
+}
+
 impl Echo<'_>
 {
-	/// `Stdout`: Affichage du log.
-	pub(super) fn log(self, text: String)
+	/// Formate un log à partir des paramètres d'un logger.
+	///
+	/// Retourne [None] lorsque le message du log est vide.
+	pub(crate) fn format(
+		entry: &LogEntry,
+		colorized: bool,
+		timestamp: bool,
+		formatter: &dyn LoggerFormatter,
+	) -> Option<String>
 	{
-		if self.record_level == log::LevelFilter::Error {
-			eprint!("{text}");
+		if entry.message.trim().is_empty() {
+			return None;
+		}
+
+		let level = if colorized {
+			match entry.level {
+				| log::Level::Error => style("ERROR").red(),
+				| log::Level::Warn => style(" WARN").yellow(),
+				| log::Level::Info => style(" INFO").blue(),
+				| log::Level::Debug => style("DEBUG").magenta(),
+				| log::Level::Trace => style("TRACE").white(),
+			}
+			.to_string()
+		} else {
+			format!("{:>5}", entry.level)
+		};
+
+		let mut table = layout::GridLayout::default().define_max_width(120).without_boarder();
+
+		let mut echo = Echo {
+			colorized,
+			delimiter: if colorized { style("|").red() } else { style("|") }.to_string(),
+			level,
+			table: &mut table,
+			time: if timestamp { Some(entry.timestamp) } else { None },
+		};
+
+		Some(formatter.format(entry, &mut echo))
+	}
+
+	/// Retire les caractères nuls du texte d'un log.
+	///
+	/// Le style par défaut de la grille utilise des caractères nuls,
+	/// invisibles dans un terminal, mais indésirables dans un fichier ou tout
+	/// autre écrivain.
+	pub(crate) fn strip_nul(text: &str) -> Cow<'_, str>
+	{
+		if text.contains('\0') {
+			Cow::Owned(text.replace('\0', ""))
 		} else {
-			print!("{text}");
+			Cow::Borrowed(text)
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::Echo;
+	use crate::LogEntry;
+
+	fn level(level: log::Level, colorized: bool) -> String
+	{
+		let entry = LogEntry::new(level, "test", "message");
+		let formatter = |_: &LogEntry, echo: &mut Echo| echo.level().to_owned();
+		Echo::format(&entry, colorized, false, &formatter).unwrap()
+	}
+
+	#[test]
+	fn level_is_aligned_on_five_characters()
+	{
+		assert_eq!(level(log::Level::Info, false), " INFO");
+		assert_eq!(level(log::Level::Warn, false), " WARN");
+		assert_eq!(level(log::Level::Error, false), "ERROR");
+		assert_eq!(console::strip_ansi_codes(&level(log::Level::Info, true)), " INFO");
+	}
+}
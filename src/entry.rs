@@ -0,0 +1,179 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::thread;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Log possédant toutes ses données.
+///
+/// Contrairement à [log::Record], un [LogEntry] peut être conservé, envoyé à
+/// un autre thread ou (dé)sérialisé avec la fonctionnalité `serde`. C'est
+/// ce log qui est donné aux formats et aux destinations.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LogEntry
+{
+	pub level: log::Level,
+	pub target: String,
+	pub module_path: Option<String>,
+	pub file: Option<String>,
+	pub line: Option<u32>,
+	pub message: String,
+	pub timestamp: chrono::DateTime<chrono::Local>,
+	/// Le nom du thread ayant émis le log, ou son identifiant lorsqu'il n'a
+	/// pas de nom.
+	pub thread: String,
+	pub key_values: Vec<(String, String)>,
+}
+
+struct KeyValues<'a>(&'a mut Vec<(String, String)>);
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LogEntry
+{
+	/// Log émis maintenant, depuis le thread courant.
+	pub fn new(level: log::Level, target: impl ToString, message: impl ToString) -> Self
+	{
+		Self {
+			level,
+			target: target.to_string(),
+			module_path: Default::default(),
+			file: Default::default(),
+			line: Default::default(),
+			message: message.to_string(),
+			timestamp: chrono::Local::now(),
+			thread: current_thread(),
+			key_values: Default::default(),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<&log::Record<'_>> for LogEntry
+{
+	fn from(record: &log::Record<'_>) -> Self
+	{
+		let mut key_values = Vec::new();
+		_ = record.key_values().visit(&mut KeyValues(&mut key_values));
+
+		Self {
+			level: record.level(),
+			target: record.target().to_owned(),
+			module_path: record.module_path().map(ToOwned::to_owned),
+			file: record.file().map(ToOwned::to_owned),
+			line: record.line(),
+			message: record.args().to_string(),
+			timestamp: chrono::Local::now(),
+			thread: current_thread(),
+			key_values,
+		}
+	}
+}
+
+impl<'kvs> log::kv::VisitSource<'kvs> for KeyValues<'_>
+{
+	fn visit_pair(&mut self, key: log::kv::Key<'kvs>, value: log::kv::Value<'kvs>) -> Result<(), log::kv::Error>
+	{
+		self.0.push((key.to_string(), value.to_string()));
+		Ok(())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+fn current_thread() -> String
+{
+	let current = thread::current();
+	match current.name() {
+		| Some(name) => name.to_owned(),
+		| None => format!("{:?}", current.id()),
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::LogEntry;
+
+	fn entry() -> LogEntry
+	{
+		let kvs = [("user", "alice"), ("attempt", "2")];
+
+		LogEntry::from(
+			&log::Record::builder()
+				.args(format_args!("hello {}", "world"))
+				.level(log::Level::Warn)
+				.target("app::auth")
+				.module_path(Some("app::auth"))
+				.file(Some("src/auth.rs"))
+				.line(Some(42))
+				.key_values(&kvs)
+				.build(),
+		)
+	}
+
+	#[test]
+	fn from_record()
+	{
+		let entry = std::thread::Builder::new()
+			.name(String::from("worker"))
+			.spawn(entry)
+			.unwrap()
+			.join()
+			.unwrap();
+
+		assert_eq!(entry.level, log::Level::Warn);
+		assert_eq!(entry.target, "app::auth");
+		assert_eq!(entry.module_path.as_deref(), Some("app::auth"));
+		assert_eq!(entry.file.as_deref(), Some("src/auth.rs"));
+		assert_eq!(entry.line, Some(42));
+		assert_eq!(entry.message, "hello world");
+		assert_eq!(entry.thread, "worker");
+		assert_eq!(entry.key_values, vec![
+			(String::from("user"), String::from("alice")),
+			(String::from("attempt"), String::from("2")),
+		]);
+	}
+
+	#[test]
+	fn unnamed_thread_uses_its_id()
+	{
+		let (entry, id) = std::thread::spawn(|| (entry(), std::thread::current().id()))
+			.join()
+			.unwrap();
+
+		assert_eq!(entry.thread, format!("{id:?}"));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_round_trip()
+	{
+		let entry = entry();
+
+		let json = serde_json::to_string(&entry).unwrap();
+		let parsed: LogEntry = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(parsed, entry);
+	}
+}
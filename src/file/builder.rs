@@ -0,0 +1,106 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path::PathBuf;
+
+use super::extension::LoggerFileBuilderExtension;
+use super::writer::FileWriter;
+use super::{FileSink, LoggerFileRetention, LoggerFileRotation, LoggerFileTimezone};
+use crate::sink::LoggerSink;
+use crate::{LoggerBackend, LoggerBackendBuilder, LoggerBackendDestination, LoggerFile};
+
+// ---- //
+// Type //
+// ---- //
+
+pub type LoggerFileBuilder = LoggerBackendBuilder<LoggerFileDestination>;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs d'un [LoggerFileBuilder]: un fichier, et sa politique
+/// de rotation.
+pub struct LoggerFileDestination
+{
+	path: PathBuf,
+	rotation: LoggerFileRotation,
+	timezone: LoggerFileTimezone,
+	retention: Option<LoggerFileRetention>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerFileDestination
+{
+	/// Le fichier n'est jamais renommé par défaut.
+	pub(crate) fn new(path: PathBuf) -> Self
+	{
+		Self {
+			path,
+			rotation: Default::default(),
+			timezone: Default::default(),
+			retention: Default::default(),
+		}
+	}
+}
+
+impl LoggerFileBuilder
+{
+	/// La politique de rotation du fichier de log.
+	pub fn with_rotation(mut self, rotation: impl Into<LoggerFileRotation>) -> Self
+	{
+		self.destination.rotation = rotation.into();
+		self
+	}
+
+	/// Le fuseau horaire des périodes d'une rotation temporelle.
+	pub fn with_timezone(mut self, timezone: impl Into<LoggerFileTimezone>) -> Self
+	{
+		self.destination.timezone = timezone.into();
+		self
+	}
+
+	/// La politique de rétention des fichiers renommés par la rotation.
+	///
+	/// Le nettoyage est effectué en arrière-plan, après chaque rotation.
+	pub fn with_retention(mut self, retention: impl Into<LoggerFileRetention>) -> Self
+	{
+		self.destination.retention.replace(retention.into());
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerBackendDestination for LoggerFileDestination
+{
+	type Logger = LoggerFile;
+
+	fn build(self, backend: impl FnOnce(Box<dyn LoggerSink>) -> LoggerBackend) -> Self::Logger
+	{
+		let retention = self
+			.retention
+			.map(|retention| retention.spawn(self.path.clone(), self.rotation));
+
+		let writer = FileWriter::new(self.path.clone(), self.rotation, self.timezone, retention);
+
+		LoggerFile {
+			backend: backend(Box::new(FileSink::new(writer))),
+			path: self.path,
+		}
+	}
+}
+
+impl LoggerFileBuilderExtension for LoggerFileBuilder {}
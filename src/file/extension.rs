@@ -0,0 +1,40 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::initiator::LoggerInitiator;
+use crate::{LoggerBuilder, LoggerGuard, LoggerFile};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait LoggerFileBuilderExtension
+	: Sized
+	+ LoggerBuilder<LoggerFile>
+{
+	fn initialize(self)
+	{
+		LoggerInitiator::file(self).expect("L'initialisation du logger (file)");
+	}
+
+	/// Initialise le logger et retourne une garde qui écrit les logs en
+	/// attente lorsqu'elle est détruite.
+	#[cfg(not(feature = "tracing"))]
+	fn try_initialize(self) -> Result<LoggerGuard, log::SetLoggerError>
+	{
+		LoggerInitiator::file(self).map(|()| LoggerGuard::new())
+	}
+
+	#[cfg(feature = "tracing")]
+	fn try_initialize(self) -> Result<LoggerGuard, &'static str>
+	{
+		LoggerInitiator::file(self).map(|()| LoggerGuard::new())
+	}
+}
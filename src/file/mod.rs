@@ -0,0 +1,155 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod builder;
+mod extension;
+mod retention;
+mod rotation;
+mod writer;
+
+use std::io::{self, Write};
+use std::ops;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+pub use self::builder::{LoggerFileBuilder, LoggerFileDestination};
+pub use self::extension::LoggerFileBuilderExtension;
+pub use self::retention::LoggerFileRetention;
+pub use self::rotation::{LoggerFileRotation, LoggerFileTimezone};
+use self::writer::{FileWriter, RolloverHandle};
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+use crate::sink::LoggerSink;
+use crate::LoggerBackend;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct LoggerFile
+{
+	pub(crate) backend: LoggerBackend,
+	pub(crate) path: PathBuf,
+}
+
+/// Destination d'un [LoggerFile]: l'écrivain du fichier, partagé avec le
+/// thread de bascule d'une rotation temporelle.
+pub(crate) struct FileSink
+{
+	writer: Arc<Mutex<FileWriter>>,
+	_rollover: Option<RolloverHandle>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerFile
+{
+	pub fn builder(path: impl Into<PathBuf>) -> LoggerFileBuilder
+	{
+		LoggerFileBuilder::with_destination(LoggerFileDestination::new(path.into()))
+	}
+}
+
+impl FileSink
+{
+	pub(crate) fn new(writer: FileWriter) -> Self
+	{
+		let writer = Arc::new(Mutex::new(writer));
+
+		// NOTE: sans le thread de bascule, le fichier est renommé au premier
+		// log qui suit la limite de la période.
+		let rollover = FileWriter::spawn_rollover(&writer).ok().flatten();
+
+		Self {
+			writer,
+			_rollover: rollover,
+		}
+	}
+}
+
+impl LoggerFile
+{
+	/// Chemin du fichier de log.
+	pub fn path(&self) -> &Path
+	{
+		&self.path
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ops::Deref for LoggerFile
+{
+	type Target = LoggerBackend;
+
+	fn deref(&self) -> &Self::Target
+	{
+		&self.backend
+	}
+}
+
+impl log::Log for LoggerFile
+{
+	fn enabled(&self, metadata: &log::Metadata) -> bool
+	{
+		self.backend.enabled(metadata)
+	}
+
+	/// Écrit le log à la fin du fichier.
+	fn log(&self, record: &log::Record)
+	{
+		self.backend.log(record);
+	}
+
+	fn flush(&self)
+	{
+		self.backend.flush();
+	}
+}
+
+impl LoggerSink for FileSink
+{
+	fn write(&self, _: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let mut writer = self.writer.lock().expect("file writer guard");
+		writer.write_all(Echo::strip_nul(text).as_bytes())
+	}
+
+	fn flush(&self) -> io::Result<()>
+	{
+		self.writer.lock().expect("file writer guard").flush()
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Un dossier temporaire vide, propre à chaque test.
+#[cfg(test)]
+pub(crate) fn temp_dir(name: &str) -> PathBuf
+{
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+	let dir = std::env::temp_dir().join(format!(
+		"lexa-logger-{name}-{}-{}",
+		std::process::id(),
+		COUNTER.fetch_add(1, Ordering::Relaxed),
+	));
+	_ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(&dir).expect("le dossier temporaire");
+	dir
+}
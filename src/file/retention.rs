@@ -0,0 +1,432 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex, TryLockError};
+use std::time::{Duration, SystemTime};
+use std::{fs, io, thread};
+
+use super::LoggerFileRotation;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Politique de rétention des fichiers de log renommés par une rotation.
+///
+/// Les limites sont cumulables: un fichier est supprimé dès qu'il dépasse
+/// l'une d'entre elles. Le fichier de log courant n'est jamais concerné.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(Eq, PartialEq)]
+pub struct LoggerFileRetention
+{
+	max_age: Option<Duration>,
+	max_count: Option<usize>,
+	max_total_size: Option<u64>,
+	#[cfg(feature = "compression")]
+	compress: bool,
+}
+
+/// Lien entre l'écrivain du fichier de log et la tâche de rétention qui
+/// tourne en arrière-plan.
+pub(crate) struct RetentionHandle
+{
+	/// Absent lorsque la tâche n'a pas pu être démarrée: le nettoyage est
+	/// alors effectué par l'écrivain, après chaque rotation.
+	sender: Option<mpsc::SyncSender<()>>,
+	lock: Arc<Mutex<()>>,
+	retention: LoggerFileRetention,
+	path: PathBuf,
+	rotation: LoggerFileRotation,
+}
+
+/// Fichier renommé par une rotation.
+struct RotatedFile
+{
+	path: PathBuf,
+	modified: SystemTime,
+	size: u64,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerFileRetention
+{
+	/// Supprime les fichiers dont la dernière modification est plus vieille
+	/// que `max_age`.
+	pub fn with_max_age(mut self, max_age: Duration) -> Self
+	{
+		self.max_age.replace(max_age);
+		self
+	}
+
+	/// Conserve au plus `max_count` fichiers, les plus récents.
+	pub fn with_max_count(mut self, max_count: usize) -> Self
+	{
+		self.max_count.replace(max_count);
+		self
+	}
+
+	/// Conserve les fichiers les plus récents dont la taille cumulée ne
+	/// dépasse pas `max_total_size` octets.
+	pub fn with_max_total_size(mut self, max_total_size: u64) -> Self
+	{
+		self.max_total_size.replace(max_total_size);
+		self
+	}
+
+	/// Compresse (gzip) les fichiers renommés par une rotation.
+	#[cfg(feature = "compression")]
+	pub fn with_compression(mut self, compress: impl Into<bool>) -> Self
+	{
+		self.compress = compress.into();
+		self
+	}
+
+	/// Démarre la tâche de rétention en arrière-plan pour le fichier de log
+	/// `path`. Un premier nettoyage est effectué au démarrage.
+	pub(crate) fn spawn(self, path: PathBuf, rotation: LoggerFileRotation) -> RetentionHandle
+	{
+		// NOTE: une seule demande en attente suffit, la tâche traite tous les
+		// fichiers à chaque passage.
+		let (sender, receiver) = mpsc::sync_channel(1);
+		let lock = Arc::new(Mutex::new(()));
+
+		let spawned = thread::Builder::new()
+			.name("lexa-logger-retention".to_owned())
+			.spawn({
+				let lock = Arc::clone(&lock);
+				let path = path.clone();
+				move || {
+					loop {
+						{
+							let _guard = lock.lock().unwrap_or_else(|err| err.into_inner());
+							_ = self.sweep(&path, rotation);
+						}
+
+						if receiver.recv().is_err() {
+							break;
+						}
+					}
+				}
+			});
+
+		let handle = RetentionHandle {
+			sender: spawned.is_ok().then_some(sender),
+			lock,
+			retention: self,
+			path,
+			rotation,
+		};
+
+		if handle.sender.is_none() {
+			handle.notify();
+		}
+
+		handle
+	}
+
+	/// Applique la politique de rétention aux fichiers renommés du fichier
+	/// de log `path`.
+	fn sweep(&self, path: &Path, rotation: LoggerFileRotation) -> io::Result<()>
+	{
+		let mut files = rotated_files(path, rotation)?;
+
+		#[cfg(feature = "compression")]
+		if self.compress {
+			for file in files.iter_mut().filter(|file| !file.is_compressed()) {
+				if let Ok(compressed) = compress(file) {
+					*file = compressed;
+				}
+			}
+		}
+
+		// NOTE: du plus récent au plus ancien.
+		files.sort_by_key(|file| std::cmp::Reverse(file.modified));
+
+		let now = SystemTime::now();
+		let mut total_size = 0;
+
+		for (idx, file) in files.iter().enumerate() {
+			total_size += file.size;
+
+			let too_old = self.max_age.is_some_and(|max_age| {
+				now.duration_since(file.modified)
+					.is_ok_and(|age| age > max_age)
+			});
+			let too_many = self.max_count.is_some_and(|max_count| idx >= max_count);
+			let too_big = self
+				.max_total_size
+				.is_some_and(|max_total_size| total_size > max_total_size);
+
+			if too_old || too_many || too_big {
+				_ = fs::remove_file(&file.path);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl RotatedFile
+{
+	#[cfg(feature = "compression")]
+	fn is_compressed(&self) -> bool
+	{
+		self.path.extension().is_some_and(|ext| ext == "gz")
+	}
+}
+
+impl RetentionHandle
+{
+	/// Verrou partagé avec la tâche de rétention.
+	///
+	/// La rotation ne doit jamais attendre la fin d'un nettoyage: si le
+	/// verrou est déjà pris, elle est simplement remise au prochain log.
+	pub(crate) fn try_lock(&self) -> Option<std::sync::MutexGuard<'_, ()>>
+	{
+		match self.lock.try_lock() {
+			| Ok(guard) => Some(guard),
+			| Err(TryLockError::Poisoned(err)) => Some(err.into_inner()),
+			| Err(TryLockError::WouldBlock) => None,
+		}
+	}
+
+	/// Demande un nouveau passage de la tâche de rétention, sans attendre.
+	///
+	/// Sans tâche de rétention, le nettoyage est effectué immédiatement.
+	pub(crate) fn notify(&self)
+	{
+		match self.sender.as_ref() {
+			| Some(sender) => _ = sender.try_send(()),
+			| None => _ = self.retention.sweep(&self.path, self.rotation),
+		}
+	}
+}
+
+/// Les fichiers renommés par une rotation du fichier de log `path` (voir
+/// [is_rotated]).
+fn rotated_files(path: &Path, rotation: LoggerFileRotation) -> io::Result<Vec<RotatedFile>>
+{
+	let directory = match path.parent() {
+		| Some(dir) if !dir.as_os_str().is_empty() => dir,
+		| _ => Path::new("."),
+	};
+
+	let mut files = Vec::default();
+
+	for entry in fs::read_dir(directory)? {
+		let entry = entry?;
+
+		let Some(name) = entry.file_name().to_str().map(ToOwned::to_owned) else {
+			continue;
+		};
+
+		let metadata = entry.metadata()?;
+		if !metadata.is_file() || !is_rotated(path, rotation, &name) {
+			continue;
+		}
+
+		files.push(RotatedFile {
+			path: entry.path(),
+			modified: metadata.modified()?,
+			size: metadata.len(),
+		});
+	}
+
+	Ok(files)
+}
+
+/// Est-ce que `name` est le nom d'un fichier renommé par la rotation du
+/// fichier de log `path`, ou de sa version compressée?
+///
+/// - Rotation par taille: `app.log.{n}`.
+/// - Rotation temporelle: `app.{période}.log` ou `app.{période}.{n}.log`, où
+///   la période est celle de la politique de rotation.
+///
+/// Les autres fichiers du dossier (`app.audit.log`, `app.log.bak`, ...) ne
+/// sont jamais concernés.
+fn is_rotated(path: &Path, rotation: LoggerFileRotation, name: &str) -> bool
+{
+	let (Some(file_name), Some(stem)) = (
+		path.file_name().and_then(|name| name.to_str()),
+		path.file_stem().and_then(|stem| stem.to_str()),
+	) else {
+		return false;
+	};
+
+	let is_number = |n: &str| !n.is_empty() && n.bytes().all(|byte| byte.is_ascii_digit());
+	let name = name.strip_suffix(".gz").unwrap_or(name);
+
+	match rotation {
+		| LoggerFileRotation::Never => false,
+		| LoggerFileRotation::Size { .. } => name
+			.strip_prefix(file_name)
+			.and_then(|rest| rest.strip_prefix('.'))
+			.is_some_and(is_number),
+		| LoggerFileRotation::Daily | LoggerFileRotation::Hourly | LoggerFileRotation::Minutely => {
+			let Some(mut rest) = name.strip_prefix(stem).and_then(|rest| rest.strip_prefix('.')) else {
+				return false;
+			};
+
+			if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
+				let Some(dated) = rest.strip_suffix(extension).and_then(|rest| rest.strip_suffix('.')) else {
+					return false;
+				};
+				rest = dated;
+			}
+
+			match rest.split_once('.') {
+				| Some((period, n)) => rotation.is_period(period) && is_number(n),
+				| None => rotation.is_period(rest),
+			}
+		}
+	}
+}
+
+/// Compresse un fichier `app.log.1` en `app.log.1.gz`, puis supprime
+/// l'original. La date de modification est conservée.
+#[cfg(feature = "compression")]
+fn compress(file: &RotatedFile) -> io::Result<RotatedFile>
+{
+	let gz_path = super::rotation::gz_path(&file.path);
+
+	let mut source = fs::File::open(&file.path)?;
+	let mut encoder = flate2::write::GzEncoder::new(fs::File::create(&gz_path)?, flate2::Compression::default());
+	io::copy(&mut source, &mut encoder)?;
+
+	let compressed = encoder.finish()?;
+	compressed.set_modified(file.modified)?;
+	let size = compressed.metadata()?.len();
+
+	fs::remove_file(&file.path)?;
+
+	Ok(RotatedFile {
+		path: gz_path,
+		modified: file.modified,
+		size,
+	})
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::fs;
+	use std::path::Path;
+	use std::time::{Duration, SystemTime};
+
+	use super::{is_rotated, LoggerFileRetention};
+	use crate::file::temp_dir;
+	use crate::LoggerFileRotation;
+
+	/// Crée un fichier modifié il y a `age` secondes.
+	fn touch(dir: &Path, name: &str, age: u64)
+	{
+		let file = fs::File::create(dir.join(name)).unwrap();
+		file.set_modified(SystemTime::now() - Duration::from_secs(age)).unwrap();
+	}
+
+	fn names(dir: &Path) -> Vec<String>
+	{
+		let mut names: Vec<_> = fs::read_dir(dir)
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name().into_string().unwrap())
+			.collect();
+		names.sort();
+		names
+	}
+
+	#[test]
+	fn is_rotated_matches_numbered_backups_only()
+	{
+		let path = Path::new("logs/app.log");
+		let size = LoggerFileRotation::size(1024, 3);
+
+		assert!(is_rotated(path, size, "app.log.1"));
+		assert!(is_rotated(path, size, "app.log.12.gz"));
+		assert!(!is_rotated(path, size, "app.log"));
+		assert!(!is_rotated(path, size, "app.log.bak"));
+		assert!(!is_rotated(path, size, "app.log."));
+		assert!(!is_rotated(path, size, "app.audit.log"));
+		assert!(!is_rotated(path, size, "app.2026-10-18.log"));
+	}
+
+	#[test]
+	fn is_rotated_matches_dated_files_only()
+	{
+		let path = Path::new("logs/app.log");
+		let daily = LoggerFileRotation::Daily;
+
+		assert!(is_rotated(path, daily, "app.2026-10-18.log"));
+		assert!(is_rotated(path, daily, "app.2026-10-18.2.log"));
+		assert!(is_rotated(path, daily, "app.2026-10-18.log.gz"));
+		assert!(!is_rotated(path, daily, "app.audit.log"));
+		assert!(!is_rotated(path, daily, "app.2026-13-45.log"));
+		assert!(!is_rotated(path, daily, "app.2026-10-18_14.log"));
+		assert!(!is_rotated(path, daily, "app.2026-10-18.bak.log"));
+		assert!(!is_rotated(path, daily, "app.2026-10-18.txt"));
+		assert!(!is_rotated(path, daily, "app.log.1"));
+
+		assert!(is_rotated(path, LoggerFileRotation::Hourly, "app.2026-10-18_14.log"));
+		assert!(is_rotated(path, LoggerFileRotation::Minutely, "app.2026-10-18_14-05.log"));
+		assert!(!is_rotated(path, LoggerFileRotation::Never, "app.log.1"));
+	}
+
+	#[test]
+	fn sweep_keeps_unrelated_sibling_files()
+	{
+		let dir = temp_dir("retention-siblings");
+		let path = dir.join("app.log");
+
+		touch(&dir, "app.log", 0);
+		touch(&dir, "app.log.1", 10);
+		touch(&dir, "app.log.2", 20);
+		touch(&dir, "app.log.3", 30);
+		touch(&dir, "app.audit.log", 40);
+		touch(&dir, "app.log.bak", 50);
+		touch(&dir, "other.log.1", 60);
+
+		LoggerFileRetention::default()
+			.with_max_count(1)
+			.sweep(&path, LoggerFileRotation::size(1024, 5))
+			.unwrap();
+
+		assert_eq!(names(&dir), ["app.audit.log", "app.log", "app.log.1", "app.log.bak", "other.log.1"]);
+	}
+
+	#[test]
+	fn sweep_applies_max_age_and_max_total_size()
+	{
+		let dir = temp_dir("retention-limits");
+		let path = dir.join("app.log");
+
+		touch(&dir, "app.2026-10-17.log", 10);
+		fs::write(dir.join("app.2026-10-16.log"), [0; 64]).unwrap();
+		touch(&dir, "app.2026-10-15.log", 3600);
+		fs::File::options()
+			.write(true)
+			.open(dir.join("app.2026-10-16.log"))
+			.unwrap()
+			.set_modified(SystemTime::now() - Duration::from_secs(20))
+			.unwrap();
+
+		LoggerFileRetention::default()
+			.with_max_age(Duration::from_secs(60))
+			.with_max_total_size(32)
+			.sweep(&path, LoggerFileRotation::Daily)
+			.unwrap();
+
+		assert_eq!(names(&dir), ["app.2026-10-17.log"]);
+	}
+}
@@ -0,0 +1,298 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::{TimeZone, Timelike};
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Politique de rotation d'un fichier de log.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(Eq, PartialEq)]
+pub enum LoggerFileRotation
+{
+	/// Le fichier de log n'est jamais renommé.
+	#[default]
+	Never,
+
+	/// Le fichier de log est renommé lorsqu'il dépasse `max_bytes` octets.
+	///
+	/// Les `backups` dernières sauvegardes sont conservées et numérotées
+	/// (`app.log.1`, `app.log.2`, ...), `app.log.1` étant la plus récente.
+	Size
+	{
+		max_bytes: u64,
+		backups: usize,
+	},
+
+	/// Le fichier de log est renommé chaque jour (`app.2026-10-18.log`).
+	Daily,
+
+	/// Le fichier de log est renommé chaque heure (`app.2026-10-18_14.log`).
+	Hourly,
+
+	/// Le fichier de log est renommé chaque minute
+	/// (`app.2026-10-18_14-05.log`).
+	Minutely,
+}
+
+/// Fuseau horaire utilisé pour découper les périodes d'une rotation
+/// temporelle.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(Eq, PartialEq)]
+pub enum LoggerFileTimezone
+{
+	#[default]
+	Local,
+	Utc,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerFileRotation
+{
+	/// Rotation par taille de fichier.
+	pub const fn size(max_bytes: u64, backups: usize) -> Self
+	{
+		Self::Size { max_bytes, backups }
+	}
+
+	/// La période, selon la politique de rotation, à laquelle appartient un
+	/// instant donné. Les politiques non temporelles n'ont pas de période.
+	pub(crate) fn period(&self, timezone: LoggerFileTimezone, time: SystemTime) -> Option<String>
+	{
+		let format = self.period_format()?;
+
+		let period = match timezone {
+			| LoggerFileTimezone::Local => chrono::DateTime::<chrono::Local>::from(time).format(format),
+			| LoggerFileTimezone::Utc => chrono::DateTime::<chrono::Utc>::from(time).format(format),
+		};
+
+		Some(period.to_string())
+	}
+
+	/// Est-ce que `period` est une période de cette politique de rotation
+	/// (`2026-10-18` pour une rotation quotidienne)?
+	pub(crate) fn is_period(&self, period: &str) -> bool
+	{
+		let Some(format) = self.period_format() else {
+			return false;
+		};
+
+		let mut parsed = chrono::format::Parsed::new();
+		chrono::format::parse(&mut parsed, period, chrono::format::StrftimeItems::new(format)).is_ok()
+			&& parsed.to_naive_date().is_ok()
+	}
+
+	/// Format (chrono) des périodes d'une rotation temporelle.
+	fn period_format(&self) -> Option<&'static str>
+	{
+		match self {
+			| Self::Never | Self::Size { .. } => None,
+			| Self::Daily => Some("%Y-%m-%d"),
+			| Self::Hourly => Some("%Y-%m-%d_%H"),
+			| Self::Minutely => Some("%Y-%m-%d_%H-%M"),
+		}
+	}
+
+	/// Le début de la période qui suit celle d'un instant donné. Les
+	/// politiques non temporelles n'ont pas de période.
+	///
+	/// Lorsque cet instant n'existe pas dans le fuseau horaire local
+	/// (changement d'heure), il n'y a pas de limite.
+	pub(crate) fn next_boundary(&self, timezone: LoggerFileTimezone, time: SystemTime) -> Option<SystemTime>
+	{
+		match timezone {
+			| LoggerFileTimezone::Local => self.next_boundary_in(chrono::DateTime::<chrono::Local>::from(time)),
+			| LoggerFileTimezone::Utc => self.next_boundary_in(chrono::DateTime::<chrono::Utc>::from(time)),
+		}
+	}
+
+	fn next_boundary_in<Tz: TimeZone>(&self, time: chrono::DateTime<Tz>) -> Option<SystemTime>
+	{
+		let local = time.naive_local();
+		let date = local.date();
+
+		let next = match self {
+			| Self::Never | Self::Size { .. } => return None,
+			| Self::Daily => date.and_hms_opt(0, 0, 0)? + chrono::Duration::days(1),
+			| Self::Hourly => date.and_hms_opt(local.hour(), 0, 0)? + chrono::Duration::hours(1),
+			| Self::Minutely => {
+				date.and_hms_opt(local.hour(), local.minute(), 0)? + chrono::Duration::minutes(1)
+			}
+		};
+
+		let next = time.timezone().from_local_datetime(&next).earliest()?;
+		Some(next.into())
+	}
+
+	/// Est-ce que le fichier doit être renommé avant d'écrire `len` octets
+	/// supplémentaires?
+	///
+	/// Pour une rotation temporelle, il suffit que la période courante ne
+	/// soit plus celle du fichier ouvert.
+	pub(crate) fn should_rotate(
+		&self,
+		timezone: LoggerFileTimezone,
+		size: u64,
+		len: usize,
+		period: Option<&str>,
+	) -> bool
+	{
+		match *self {
+			| Self::Never => false,
+			| Self::Size { max_bytes, .. } => size > 0 && size + len as u64 > max_bytes,
+			| Self::Daily | Self::Hourly | Self::Minutely => {
+				size > 0 && self.period(timezone, SystemTime::now()).as_deref() != period
+			}
+		}
+	}
+
+	/// Renomme le fichier de log.
+	///
+	/// - Rotation par taille: les sauvegardes numérotées sont décalées.
+	/// - Rotation temporelle: le fichier est daté avec sa période.
+	///
+	/// Chaque étape est un [std::fs::rename], qui remplace atomiquement la
+	/// cible sur un même système de fichiers.
+	pub(crate) fn rotate(&self, path: &Path, period: Option<&str>) -> std::io::Result<()>
+	{
+		match *self {
+			| Self::Never => Ok(()),
+			| Self::Size { backups: 0, .. } => std::fs::remove_file(path),
+			| Self::Size { backups, .. } => {
+				for n in (1..backups).rev() {
+					let (from, to) = (numbered_path(path, n), numbered_path(path, n + 1));
+
+					// NOTE: une sauvegarde a pu être compressée par la
+					// politique de rétention.
+					for (from, to) in [(gz_path(&from), gz_path(&to)), (from, to)] {
+						if from.exists() {
+							std::fs::rename(from, to)?;
+						}
+					}
+				}
+
+				std::fs::rename(path, numbered_path(path, 1))
+			}
+			| Self::Daily | Self::Hourly | Self::Minutely => {
+				let Some(period) = period else {
+					return Ok(());
+				};
+
+				let mut to = dated_path(path, period, None);
+				let mut n = 0;
+				while to.exists() {
+					n += 1;
+					to = dated_path(path, period, Some(n));
+				}
+
+				std::fs::rename(path, to)
+			}
+		}
+	}
+}
+
+/// `app.log` -> `app.log.{n}`
+pub(crate) fn numbered_path(path: &Path, n: usize) -> PathBuf
+{
+	let mut numbered = OsString::from(path.as_os_str());
+	numbered.push(format!(".{n}"));
+	PathBuf::from(numbered)
+}
+
+/// `app.log.1` -> `app.log.1.gz`
+pub(crate) fn gz_path(path: &Path) -> PathBuf
+{
+	let mut compressed = OsString::from(path.as_os_str());
+	compressed.push(".gz");
+	PathBuf::from(compressed)
+}
+
+/// `app.log` -> `app.{period}.log` (`app.{period}.{n}.log` en cas de
+/// collision)
+pub(crate) fn dated_path(path: &Path, period: &str, n: Option<usize>) -> PathBuf
+{
+	let mut name = path.file_stem().map(OsString::from).unwrap_or_default();
+
+	name.push(format!(".{period}"));
+
+	if let Some(n) = n {
+		name.push(format!(".{n}"));
+	}
+
+	if let Some(extension) = path.extension() {
+		name.push(".");
+		name.push(extension);
+	}
+
+	path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::path::Path;
+	use std::time::{Duration, SystemTime};
+
+	use super::{dated_path, LoggerFileRotation, LoggerFileTimezone};
+
+	/// 2026-10-18T14:05:30Z
+	fn instant() -> SystemTime
+	{
+		SystemTime::UNIX_EPOCH + Duration::from_secs(1_792_332_330)
+	}
+
+	fn boundary(rotation: LoggerFileRotation) -> String
+	{
+		let next = rotation.next_boundary(LoggerFileTimezone::Utc, instant()).unwrap();
+		chrono::DateTime::<chrono::Utc>::from(next).to_rfc3339()
+	}
+
+	#[test]
+	fn period_follows_the_policy()
+	{
+		let period = |rotation: LoggerFileRotation| rotation.period(LoggerFileTimezone::Utc, instant());
+
+		assert_eq!(period(LoggerFileRotation::Daily).as_deref(), Some("2026-10-18"));
+		assert_eq!(period(LoggerFileRotation::Hourly).as_deref(), Some("2026-10-18_14"));
+		assert_eq!(period(LoggerFileRotation::Minutely).as_deref(), Some("2026-10-18_14-05"));
+		assert_eq!(period(LoggerFileRotation::size(1, 1)), None);
+	}
+
+	#[test]
+	fn next_boundary_starts_the_next_period()
+	{
+		assert_eq!(boundary(LoggerFileRotation::Daily), "2026-10-19T00:00:00+00:00");
+		assert_eq!(boundary(LoggerFileRotation::Hourly), "2026-10-18T15:00:00+00:00");
+		assert_eq!(boundary(LoggerFileRotation::Minutely), "2026-10-18T14:06:00+00:00");
+		assert_eq!(LoggerFileRotation::Never.next_boundary(LoggerFileTimezone::Utc, instant()), None);
+	}
+
+	#[test]
+	fn dated_path_keeps_the_extension()
+	{
+		let path = Path::new("logs/app.log");
+
+		assert_eq!(dated_path(path, "2026-10-18", None), Path::new("logs/app.2026-10-18.log"));
+		assert_eq!(dated_path(path, "2026-10-18", Some(2)), Path::new("logs/app.2026-10-18.2.log"));
+	}
+}
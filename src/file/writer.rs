@@ -0,0 +1,339 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use super::retention::RetentionHandle;
+use super::{LoggerFileRotation, LoggerFileTimezone};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Écrivain du fichier de log.
+///
+/// Le fichier est ouvert (et ses dossiers parents créés) à la première
+/// écriture, en mode ajout. Il est fermé puis renommé selon la politique de
+/// rotation.
+pub(crate) struct FileWriter
+{
+	path: PathBuf,
+	file: Option<fs::File>,
+	size: u64,
+	period: Option<String>,
+	rotation: LoggerFileRotation,
+	timezone: LoggerFileTimezone,
+	retention: Option<RetentionHandle>,
+}
+
+/// Thread qui renomme le fichier de log à la limite de chaque période d'une
+/// rotation temporelle, même si aucun log n'est écrit à ce moment-là.
+///
+/// Le thread s'arrête lorsque cette structure est détruite.
+pub(crate) struct RolloverHandle
+{
+	_stop: mpsc::Sender<()>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl FileWriter
+{
+	pub(crate) fn new(
+		path: impl Into<PathBuf>,
+		rotation: LoggerFileRotation,
+		timezone: LoggerFileTimezone,
+		retention: Option<RetentionHandle>,
+	) -> Self
+	{
+		Self {
+			path: path.into(),
+			file: None,
+			size: 0,
+			period: None,
+			rotation,
+			timezone,
+			retention,
+		}
+	}
+
+	/// Le fichier de log, ouvert si besoin.
+	fn file(&mut self) -> io::Result<&mut fs::File>
+	{
+		if self.file.is_none() {
+			if let Some(parent) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+				fs::create_dir_all(parent)?;
+			}
+
+			let file = fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+			let metadata = file.metadata()?;
+
+			// NOTE: un fichier existant appartient à la période de sa dernière
+			// modification, ce qui permet de le renommer correctement après un
+			// redémarrage.
+			let modified = if metadata.len() > 0 {
+				metadata.modified()?
+			} else {
+				SystemTime::now()
+			};
+
+			self.size = metadata.len();
+			self.period = self.rotation.period(self.timezone, modified);
+			self.file.replace(file);
+		}
+
+		Ok(self.file.as_mut().expect("le fichier de log ouvert"))
+	}
+
+	/// Démarre le thread de bascule d'une rotation temporelle. Les autres
+	/// politiques n'en ont pas besoin.
+	pub(crate) fn spawn_rollover(writer: &Arc<Mutex<Self>>) -> io::Result<Option<RolloverHandle>>
+	{
+		let (rotation, timezone) = {
+			let writer = writer.lock().expect("file writer guard");
+			(writer.rotation, writer.timezone)
+		};
+
+		if rotation.next_boundary(timezone, SystemTime::now()).is_none() {
+			return Ok(None);
+		}
+
+		/// Durée maximale d'attente: la limite est recalculée au moins une
+		/// fois par heure (changement d'heure, horloge système modifiée).
+		const MAX_WAIT: Duration = Duration::from_secs(60 * 60);
+
+		let (stop, stopped) = mpsc::channel::<()>();
+		let writer = Arc::downgrade(writer);
+
+		thread::Builder::new()
+			.name("lexa-logger-rollover".to_owned())
+			.spawn(move || {
+				loop {
+					let now = SystemTime::now();
+					let wait = rotation
+						.next_boundary(timezone, now)
+						.and_then(|boundary| boundary.duration_since(now).ok())
+						.map_or(MAX_WAIT, |wait| wait.min(MAX_WAIT));
+
+					if let Err(mpsc::RecvTimeoutError::Disconnected) = stopped.recv_timeout(wait) {
+						break;
+					}
+
+					let Some(writer) = writer.upgrade() else {
+						break;
+					};
+
+					_ = writer.lock().expect("file writer guard").rollover();
+				}
+			})?;
+
+		Ok(Some(RolloverHandle { _stop: stop }))
+	}
+
+	/// Renomme le fichier de log si sa période est terminée.
+	fn rollover(&mut self) -> io::Result<()>
+	{
+		if self.file.is_none() && !self.path.exists() {
+			return Ok(());
+		}
+
+		self.rotate_if_needed(0)
+	}
+
+	/// Effectue la rotation du fichier avant d'y écrire `len` octets, si la
+	/// politique de rotation le demande.
+	fn rotate_if_needed(&mut self, len: usize) -> io::Result<()>
+	{
+		self.file()?;
+
+		if !self
+			.rotation
+			.should_rotate(self.timezone, self.size, len, self.period.as_deref())
+		{
+			return Ok(());
+		}
+
+		// NOTE: si la tâche de rétention est en cours, la rotation est remise
+		// au prochain log plutôt que d'attendre la fin du nettoyage.
+		let retention = self.retention.take();
+
+		let result = match retention.as_ref().map(|handle| handle.try_lock()) {
+			| Some(None) => None,
+			| Some(Some(_guard)) => Some(self.rotate()),
+			| None => Some(self.rotate()),
+		};
+
+		if let Some(handle) = retention {
+			// NOTE: rien de nouveau à nettoyer si la rotation a été remise.
+			if let Some(Ok(())) = result {
+				handle.notify();
+			}
+			self.retention.replace(handle);
+		}
+
+		result.unwrap_or(Ok(()))
+	}
+
+	/// Ferme puis renomme le fichier de log.
+	fn rotate(&mut self) -> io::Result<()>
+	{
+		if let Some(mut file) = self.file.take() {
+			file.flush()?;
+		}
+
+		self.size = 0;
+		let period = self.period.take();
+		self.rotation.rotate(&self.path, period.as_deref())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Write for FileWriter
+{
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+	{
+		self.rotate_if_needed(buf.len())?;
+		let n = self.file()?.write(buf)?;
+		self.size += n as u64;
+		Ok(n)
+	}
+
+	fn write_all(&mut self, buf: &[u8]) -> io::Result<()>
+	{
+		self.rotate_if_needed(buf.len())?;
+		self.file()?.write_all(buf)?;
+		self.size += buf.len() as u64;
+		Ok(())
+	}
+
+	fn flush(&mut self) -> io::Result<()>
+	{
+		match self.file.as_mut() {
+			| Some(file) => file.flush(),
+			| None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::fs;
+	use std::io::Write;
+
+	use super::FileWriter;
+	use crate::file::temp_dir;
+	use crate::{LoggerFileRotation, LoggerFileTimezone};
+
+	#[test]
+	fn size_rotation_shifts_numbered_backups()
+	{
+		let dir = temp_dir("size-rotation");
+		let path = dir.join("app.log");
+		let mut writer = FileWriter::new(&path, LoggerFileRotation::size(10, 2), LoggerFileTimezone::Utc, None);
+
+		for line in ["first ---\n", "second --\n", "third ---\n", "fourth --\n"] {
+			writer.write_all(line.as_bytes()).unwrap();
+		}
+		writer.flush().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "fourth --\n");
+		assert_eq!(fs::read_to_string(dir.join("app.log.1")).unwrap(), "third ---\n");
+		assert_eq!(fs::read_to_string(dir.join("app.log.2")).unwrap(), "second --\n");
+		assert!(!dir.join("app.log.3").exists());
+	}
+
+	#[test]
+	fn size_rotation_keeps_the_file_under_the_limit()
+	{
+		let dir = temp_dir("size-limit");
+		let path = dir.join("app.log");
+		let mut writer = FileWriter::new(&path, LoggerFileRotation::size(12, 1), LoggerFileTimezone::Utc, None);
+
+		writer.write_all(b"12345\n").unwrap();
+		writer.write_all(b"12345\n").unwrap();
+		writer.write_all(b"12345\n").unwrap();
+		writer.flush().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "12345\n");
+		assert_eq!(fs::read_to_string(dir.join("app.log.1")).unwrap(), "12345\n12345\n");
+	}
+
+	#[test]
+	fn size_rotation_without_backups_truncates()
+	{
+		let dir = temp_dir("size-no-backup");
+		let path = dir.join("app.log");
+		let mut writer = FileWriter::new(&path, LoggerFileRotation::size(4, 0), LoggerFileTimezone::Utc, None);
+
+		writer.write_all(b"old\n").unwrap();
+		writer.write_all(b"new\n").unwrap();
+		writer.flush().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "new\n");
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+	}
+
+	#[test]
+	fn rollover_renames_the_file_of_a_finished_period()
+	{
+		let dir = temp_dir("rollover");
+		let path = dir.join("app.log");
+		let mut writer = FileWriter::new(&path, LoggerFileRotation::Daily, LoggerFileTimezone::Utc, None);
+
+		writer.write_all(b"yesterday\n").unwrap();
+		writer.period.replace("2000-01-01".to_owned());
+		writer.rollover().unwrap();
+
+		assert!(!path.exists());
+		assert_eq!(fs::read_to_string(dir.join("app.2000-01-01.log")).unwrap(), "yesterday\n");
+
+		// NOTE: rien à renommer tant qu'aucun log n'est écrit.
+		writer.rollover().unwrap();
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+	}
+
+	#[test]
+	fn rollover_keeps_the_file_of_the_current_period()
+	{
+		let dir = temp_dir("rollover-current");
+		let path = dir.join("app.log");
+		let mut writer = FileWriter::new(&path, LoggerFileRotation::Daily, LoggerFileTimezone::Utc, None);
+
+		writer.write_all(b"today\n").unwrap();
+		writer.rollover().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "today\n");
+	}
+
+	#[test]
+	fn size_rotation_does_not_rotate_an_empty_file()
+	{
+		let dir = temp_dir("size-oversized");
+		let path = dir.join("app.log");
+		let mut writer = FileWriter::new(&path, LoggerFileRotation::size(4, 1), LoggerFileTimezone::Utc, None);
+
+		writer.write_all(b"longer than the limit\n").unwrap();
+		writer.flush().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "longer than the limit\n");
+		assert!(!dir.join("app.log.1").exists());
+	}
+}
@@ -0,0 +1,72 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::builder::LoggerFilterCallback;
+
+// --------- //
+// Structure //
+// --------- //
+
+#[derive(Default)]
+pub struct LoggerFilter
+{
+	callbacks: Vec<Box<LoggerFilterCallback>>,
+	dependencies: Vec<String>,
+	cache: Arc<Mutex<HashMap<String, bool>>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerFilter
+{
+	/// Pousse une callback dans le tableau des callbacks.
+	pub(crate) fn push_callback<F>(&mut self, predicate: F)
+	where
+		F: 'static,
+		F: Send + Sync,
+		F: Fn(&log::Metadata) -> bool,
+	{
+		self.callbacks.push(Box::new(predicate));
+	}
+
+	/// Ajoute une dépendance dans le tableau des dépendances.
+	pub(crate) fn add_dependency(&mut self, dependency: impl ToString)
+	{
+		self.dependencies.push(dependency.to_string());
+	}
+
+	/// Vérifie que les métadonnées d'un log passent au moins un des filtres
+	/// utilisateurs. Le résultat est mis en cache par dépendance et par cible.
+	pub(crate) fn is_enabled(&self, metadata: &log::Metadata) -> bool
+	{
+		if self.callbacks.is_empty() {
+			return true;
+		}
+
+		let mut guard = self.cache.lock().expect("cache guard");
+
+		self.callbacks.iter().enumerate().any(|(idx, once_fn)| {
+			let cache_key = format!("{}_{}", self.dependencies[idx], metadata.target());
+
+			if let Some(has) = guard.get(&cache_key) {
+				return *has;
+			}
+
+			let is_ok = once_fn(metadata);
+			guard.insert(cache_key, is_ok);
+			is_ok
+		})
+	}
+}
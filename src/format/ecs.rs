@@ -0,0 +1,225 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::json::JsonObject;
+use super::{hostname, LoggerFormatter};
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format ECS (Elastic Common Schema) : un objet JSON par log, sans aucun
+/// code ANSI, prêt à être ingéré par Elasticsearch ou Filebeat.
+///
+/// Les champs sont `@timestamp` (en UTC), `log.level`, `log.logger` (la
+/// cible), `log.origin.file.name`, `log.origin.file.line`, `message`,
+/// `process.pid`, `process.thread.name`, `host.hostname` et `ecs.version`.
+/// Les champs `log.origin.*` sont omis lorsque le log ne les contient pas.
+/// Les paires clé-valeur du log sont placées dans `labels` ; les points,
+/// espaces et caractères de contrôle de leurs clés sont remplacés par `_`,
+/// ECS n'acceptant pas de point dans le nom d'un label.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct LoggerEcsFormat
+{
+	hostname: String,
+	service_name: Option<String>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerEcsFormat
+{
+	/// La version d'ECS respectée par ce format.
+	pub const ECS_VERSION: &'static str = "8.11.0";
+
+	/// Le nom de la machine émettant les logs. Par défaut, le nom d'hôte du
+	/// système.
+	pub fn with_hostname(mut self, hostname: impl ToString) -> Self
+	{
+		self.hostname = hostname.to_string();
+		self
+	}
+
+	/// Le nom du service, le champ `service.name`. Par défaut, il n'est pas
+	/// renseigné.
+	pub fn with_service_name(mut self, name: impl ToString) -> Self
+	{
+		self.service_name.replace(name.to_string());
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LoggerEcsFormat
+{
+	fn default() -> Self
+	{
+		Self {
+			hostname: hostname(),
+			service_name: Default::default(),
+		}
+	}
+}
+
+impl LoggerFormatter for LoggerEcsFormat
+{
+	fn format(&self, entry: &LogEntry, _: &mut Echo) -> String
+	{
+		let timestamp = entry
+			.timestamp
+			.with_timezone(&chrono::Utc)
+			.to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+		let mut object = JsonObject::new();
+		object
+			.string("@timestamp", &timestamp)
+			.string("log.level", &entry.level.as_str().to_lowercase())
+			.string("log.logger", &entry.target);
+
+		if let Some(file) = entry.file.as_deref() {
+			object.string("log.origin.file.name", file);
+		}
+		if let Some(line) = entry.line {
+			object.number("log.origin.file.line", Some(line));
+		}
+
+		object
+			.string("message", &console::strip_ansi_codes(&entry.message))
+			.number("process.pid", Some(std::process::id()))
+			.string("process.thread.name", &entry.thread)
+			.string("host.hostname", &self.hostname);
+
+		if let Some(service_name) = self.service_name.as_deref() {
+			object.string("service.name", service_name);
+		}
+
+		if !entry.key_values.is_empty() {
+			let mut labels = JsonObject::new();
+			for (key, value) in entry.key_values.iter() {
+				labels.string(&label(key), value);
+			}
+			object.raw("labels", &labels.finish());
+		}
+
+		let mut line = object.string("ecs.version", Self::ECS_VERSION).finish();
+		line.push('\n');
+		line
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Le nom d'un label ECS : les points, espaces et caractères de contrôle sont
+/// remplacés par `_`.
+fn label(key: &str) -> String
+{
+	if key.is_empty() {
+		return String::from("_");
+	}
+
+	key.chars()
+		.map(|ch| {
+			if ch == '.' || ch.is_whitespace() || ch.is_control() {
+				'_'
+			} else {
+				ch
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{label, LoggerEcsFormat};
+	use crate::{Echo, LogEntry};
+
+	fn entry() -> LogEntry
+	{
+		let mut entry = LogEntry::new(log::Level::Warn, "app::db", "\u{1b}[1mslow\u{1b}[0m query");
+		entry.timestamp = chrono::DateTime::from_timestamp(1_792_332_330, 123_000_000)
+			.unwrap()
+			.with_timezone(&chrono::Local);
+		entry.thread = String::from("main");
+		entry
+	}
+
+	#[test]
+	fn fields()
+	{
+		let format = LoggerEcsFormat::default().with_hostname("host");
+		let line = Echo::format(&entry(), true, true, &format).unwrap();
+
+		assert_eq!(
+			line,
+			format!(
+				concat!(
+					r#"{{"@timestamp":"2026-10-18T14:05:30.123Z","log.level":"warn","log.logger":"app::db","#,
+					r#""message":"slow query","process.pid":{},"process.thread.name":"main","#,
+					r#""host.hostname":"host","ecs.version":"{}"}}"#,
+					"\n"
+				),
+				std::process::id(),
+				LoggerEcsFormat::ECS_VERSION
+			)
+		);
+	}
+
+	#[test]
+	fn location_and_labels()
+	{
+		let mut entry = entry();
+		entry.file = Some(String::from("src/db.rs"));
+		entry.line = Some(42);
+		entry.key_values.push((String::from("db.table"), String::from("users")));
+		entry.key_values.push((String::from("user id"), String::from("7")));
+
+		let format = LoggerEcsFormat::default().with_hostname("host").with_service_name("api");
+		let line = Echo::format(&entry, false, false, &format).unwrap();
+
+		assert!(line.contains(r#""log.origin.file.name":"src/db.rs","log.origin.file.line":42,"#), "{line}");
+		assert!(line.contains(r#""service.name":"api","#), "{line}");
+		assert!(line.contains(r#""labels":{"db_table":"users","user_id":"7"}"#), "{line}");
+		assert!(!line.contains("null"), "{line}");
+	}
+
+	#[test]
+	fn labels_are_sanitized()
+	{
+		assert_eq!(label("user"), "user");
+		assert_eq!(label("a.b c\n"), "a_b_c_");
+		assert_eq!(label(""), "_");
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn selected_from_settings()
+	{
+		let settings: crate::Settings = serde_json::from_str(
+			r#"{"colorized":false,"max_level":"INFO","target_filters":[],"timestamp":true,"format":"ECS"}"#,
+		)
+		.unwrap();
+
+		let line = Echo::format(&entry(), settings.colorized, settings.timestamp, &settings.formatter().unwrap()).unwrap();
+
+		assert!(line.starts_with(r#"{"@timestamp":"2026-10-18T14:05:30.123Z","log.level":"warn","#), "{line}");
+		assert!(line.ends_with(&format!("\"ecs.version\":\"{}\"}}\n", LoggerEcsFormat::ECS_VERSION)), "{line}");
+	}
+}
@@ -0,0 +1,214 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::json::JsonObject;
+use super::{hostname, syslog_severity, LoggerFormatter};
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format GELF 1.1 (Graylog Extended Log Format) : un objet JSON par log,
+/// sans aucun code ANSI.
+///
+/// Le `short_message` est la première ligne non vide du message ; le message
+/// complet est envoyé dans `full_message` lorsqu'il tient sur plusieurs
+/// lignes. Le niveau est celui de syslog, et les données du log (`target`,
+/// `module`, `file`, `line`, `thread` ainsi que les paires clé-valeur) sont
+/// des champs additionnels préfixés par `_`, omis lorsque le log ne les
+/// contient pas. Une paire clé-valeur portant le nom de l'un
+/// de ces champs (`target`, ...) reçoit un `_` supplémentaire (`__target`).
+///
+/// Voir [crate::LoggerGelfTransport] pour envoyer les logs à Graylog.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct LoggerGelfFormat
+{
+	host: String,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerGelfFormat
+{
+	/// Le nom de la machine émettant les logs. Par défaut, le nom d'hôte du
+	/// système.
+	pub fn with_host(mut self, host: impl ToString) -> Self
+	{
+		self.host = host.to_string();
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LoggerGelfFormat
+{
+	fn default() -> Self
+	{
+		Self { host: hostname() }
+	}
+}
+
+impl LoggerFormatter for LoggerGelfFormat
+{
+	fn format(&self, entry: &LogEntry, _: &mut Echo) -> String
+	{
+		let message = console::strip_ansi_codes(&entry.message);
+		let message = message.trim_end();
+		// NOTE: GELF exige un `short_message` non vide.
+		let short_message = message
+			.lines()
+			.find(|line| !line.trim().is_empty())
+			.unwrap_or("-");
+		let millis = entry.timestamp.timestamp_millis();
+
+		let mut object = JsonObject::new();
+		object
+			.string("version", "1.1")
+			.string("host", &self.host)
+			.string("short_message", short_message);
+
+		if message.contains('\n') {
+			object.string("full_message", message);
+		}
+
+		object
+			.number("timestamp", Some(format!("{}.{:03}", millis.div_euclid(1000), millis.rem_euclid(1000))))
+			.number("level", Some(syslog_severity(entry.level)))
+			.string("_target", &entry.target);
+
+		// NOTE: GELF n'accepte que des chaînes ou des nombres dans les champs
+		// additionnels, pas `null`.
+		if let Some(module) = entry.module_path.as_deref() {
+			object.string("_module", module);
+		}
+		if let Some(file) = entry.file.as_deref() {
+			object.string("_file", file);
+		}
+		if let Some(line) = entry.line {
+			object.number("_line", Some(line));
+		}
+
+		object.string("_thread", &entry.thread);
+
+		for (key, value) in entry.key_values.iter() {
+			object.string(&additional_field(key), value);
+		}
+
+		let mut line = object.finish();
+		line.push('\n');
+		line
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Le nom d'un champ additionnel GELF : préfixé par `_`, composé uniquement
+/// de lettres, de chiffres, de `_`, `.` et `-`. Le champ `_id` étant réservé
+/// par GELF, et les autres par ce format, ils reçoivent un `_` supplémentaire
+/// (`__id`, `__target`, ...).
+fn additional_field(key: &str) -> String
+{
+	const RESERVED: [&str; 6] = ["_id", "_target", "_module", "_file", "_line", "_thread"];
+
+	let mut field = String::with_capacity(key.len() + 1);
+	field.push('_');
+	field.extend(key.chars().map(|ch| {
+		if ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '-') {
+			ch
+		} else {
+			'_'
+		}
+	}));
+
+	if RESERVED.contains(&field.as_str()) {
+		field.insert(0, '_');
+	}
+
+	field
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{additional_field, LoggerGelfFormat};
+	use crate::{Echo, LogEntry};
+
+	#[test]
+	fn additional_fields_are_sanitized()
+	{
+		assert_eq!(additional_field("user"), "_user");
+		assert_eq!(additional_field("user id/é"), "_user_id__");
+		assert_eq!(additional_field("a.b-c_d"), "_a.b-c_d");
+		assert_eq!(additional_field("id"), "__id");
+		assert_eq!(additional_field("target"), "__target");
+		assert_eq!(additional_field("thread"), "__thread");
+	}
+
+	#[test]
+	fn key_values_do_not_shadow_builtin_fields()
+	{
+		let mut entry = LogEntry::new(log::Level::Error, "app", "first\nsecond \"quoted\"");
+		entry.key_values.push(("target".to_owned(), "db".to_owned()));
+
+		let format = LoggerGelfFormat::default().with_host("host");
+		let line = Echo::format(&entry, true, false, &format).unwrap();
+
+		assert!(line.starts_with(r#"{"version":"1.1","host":"host","short_message":"first","#), "{line}");
+		assert!(line.contains(r#""full_message":"first\nsecond \"quoted\"""#), "{line}");
+		assert!(line.contains(r#""level":3"#), "{line}");
+		assert!(line.contains(r#""_target":"app""#), "{line}");
+		assert!(line.contains(r#""__target":"db""#), "{line}");
+		assert_eq!(line.matches(r#""_target""#).count(), 1);
+	}
+
+	#[test]
+	fn location_is_omitted_when_absent()
+	{
+		let mut entry = LogEntry::new(log::Level::Info, "app", "hello");
+		let format = LoggerGelfFormat::default().with_host("host");
+
+		let line = Echo::format(&entry, false, false, &format).unwrap();
+		assert!(!line.contains("null"), "{line}");
+		assert!(!line.contains("_module") && !line.contains("_file") && !line.contains("_line"), "{line}");
+
+		entry.module_path = Some("app::db".to_owned());
+		entry.file = Some("src/db.rs".to_owned());
+		entry.line = Some(42);
+
+		let line = Echo::format(&entry, false, false, &format).unwrap();
+		assert!(line.contains(r#""_module":"app::db","_file":"src/db.rs","_line":42,"#), "{line}");
+	}
+
+	#[test]
+	fn short_message_is_the_first_non_blank_line()
+	{
+		let entry = LogEntry::new(log::Level::Info, "app", "\n  \nhello\nworld");
+		let format = LoggerGelfFormat::default().with_host("host");
+
+		let line = Echo::format(&entry, false, false, &format).unwrap();
+		assert!(line.contains(r#""short_message":"hello","#), "{line}");
+		assert!(line.contains(r#""full_message":"\n  \nhello\nworld""#), "{line}");
+
+		let entry = LogEntry::new(log::Level::Info, "app", "\u{1b}[1m\u{1b}[0m");
+		let line = Echo::format(&entry, false, false, &format).unwrap();
+		assert!(line.contains(r#""short_message":"-","#), "{line}");
+		assert!(!line.contains("full_message"), "{line}");
+	}
+}
@@ -0,0 +1,177 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::{LoggerFormatter, LoggerTemplateError, LoggerTemplateFormat};
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format des logs en colonnes : le niveau, la date, la cible et le message.
+///
+/// Raccourci vers un [LoggerTemplateFormat], dont le template est construit à
+/// partir des options. Par défaut, il s'agit de
+/// [LoggerTemplateFormat::DEFAULT].
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct LoggerGridFormat
+{
+	date_format: String,
+	target: bool,
+	key_values: bool,
+	format: LoggerTemplateFormat,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerGridFormat
+{
+	const DATE_FORMAT: &'static str = "%Y-%m-%d@%H:%M:%S";
+
+	/// Format de la date (voir [chrono::format::strftime]). Par défaut,
+	/// `%Y-%m-%d@%H:%M:%S`.
+	///
+	/// Retourne [LoggerTemplateError::InvalidSpec] si le format de la date
+	/// est invalide, ou contient une accolade, que le template ne peut pas
+	/// contenir dans un champ.
+	pub fn with_date_format(mut self, date_format: impl ToString) -> Result<Self, LoggerTemplateError>
+	{
+		let date_format = date_format.to_string();
+		if date_format.contains(['{', '}']) {
+			return Err(LoggerTemplateError::InvalidSpec(date_format));
+		}
+
+		self.date_format = date_format;
+		self.format = self.template_format()?;
+		Ok(self)
+	}
+
+	/// Affiche ou non la cible du log. Par défaut, la cible est affichée.
+	pub fn with_target(mut self, b: impl Into<bool>) -> Self
+	{
+		self.target = b.into();
+		self.rebuild()
+	}
+
+	/// Affiche ou non les paires clé-valeur du log à la suite du message.
+	/// Par défaut, elles ne sont pas affichées.
+	pub fn with_key_values(mut self, b: impl Into<bool>) -> Self
+	{
+		self.key_values = b.into();
+		self.rebuild()
+	}
+
+	/// Le template construit à partir des options.
+	pub fn template(&self) -> &str
+	{
+		self.format.template()
+	}
+}
+
+impl LoggerGridFormat
+{
+	fn rebuild(mut self) -> Self
+	{
+		// NOTE: le format de la date a été validé par `with_date_format`.
+		self.format = self.template_format().expect("Le format de la date");
+		self
+	}
+
+	fn template_format(&self) -> Result<LoggerTemplateFormat, LoggerTemplateError>
+	{
+		let time = if self.date_format == Self::DATE_FORMAT {
+			String::from("{time}")
+		} else {
+			format!("{{time:{}}}", self.date_format)
+		};
+		let target = if self.target { "{target:black.bright} {\"->\":red} " } else { "" };
+		let key_values = if self.key_values { " {kv}" } else { "" };
+
+		LoggerTemplateFormat::new(format!(
+			"{{level:>5}} {{delimiter}} {time} {{delimiter}} {target}{{message}}{key_values}"
+		))
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LoggerGridFormat
+{
+	fn default() -> Self
+	{
+		Self {
+			date_format: String::from(Self::DATE_FORMAT),
+			target: true,
+			key_values: false,
+			format: LoggerTemplateFormat::default(),
+		}
+	}
+}
+
+impl From<LoggerGridFormat> for LoggerTemplateFormat
+{
+	fn from(grid: LoggerGridFormat) -> Self
+	{
+		grid.format
+	}
+}
+
+impl LoggerFormatter for LoggerGridFormat
+{
+	fn format(&self, entry: &LogEntry, echo: &mut Echo) -> String
+	{
+		self.format.format(entry, echo)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::LoggerGridFormat;
+	use crate::{LoggerTemplateError, LoggerTemplateFormat};
+
+	#[test]
+	fn default_grid_is_the_default_template()
+	{
+		let grid = LoggerGridFormat::default();
+
+		assert_eq!(grid.template(), LoggerTemplateFormat::DEFAULT);
+		assert_eq!(grid.clone().with_target(true).template(), LoggerTemplateFormat::DEFAULT);
+	}
+
+	#[test]
+	fn options_build_the_template()
+	{
+		let grid = LoggerGridFormat::default()
+			.with_date_format("%H:%M")
+			.unwrap()
+			.with_target(false)
+			.with_key_values(true);
+
+		assert_eq!(grid.template(), "{level:>5} {delimiter} {time:%H:%M} {delimiter} {message} {kv}");
+	}
+
+	#[test]
+	fn invalid_date_format_is_an_error()
+	{
+		for date_format in ["%H}", "{%H", "%Q"] {
+			assert_eq!(
+				LoggerGridFormat::default().with_date_format(date_format).unwrap_err(),
+				LoggerTemplateError::InvalidSpec(date_format.to_owned())
+			);
+		}
+	}
+}
@@ -0,0 +1,198 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Write;
+
+use super::LoggerFormatter;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format JSON Lines : un objet JSON par log, sur une seule ligne, sans
+/// aucun code ANSI, quels que soient les paramètres du logger.
+///
+/// ```json
+/// {"timestamp":"…","level":"INFO","target":"app","module":"app","file":"src/main.rs","line":4,"thread":"main","message":"…","key_values":{"user":"…"}}
+/// ```
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct LoggerJsonFormat;
+
+/// Objet JSON en cours d'écriture.
+pub(crate) struct JsonObject
+{
+	buffer: String,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl JsonObject
+{
+	pub(crate) fn new() -> Self
+	{
+		Self {
+			buffer: String::from("{"),
+		}
+	}
+
+	fn key(&mut self, key: &str)
+	{
+		if self.buffer.len() > 1 {
+			self.buffer.push(',');
+		}
+		push_string(&mut self.buffer, key);
+		self.buffer.push(':');
+	}
+
+	/// Ajoute une chaîne de caractères.
+	pub(crate) fn string(&mut self, key: &str, value: &str) -> &mut Self
+	{
+		self.key(key);
+		push_string(&mut self.buffer, value);
+		self
+	}
+
+	/// Ajoute une chaîne de caractères, ou `null`.
+	pub(crate) fn optional_string(&mut self, key: &str, value: Option<&str>) -> &mut Self
+	{
+		match value {
+			| Some(value) => self.string(key, value),
+			| None => self.raw(key, "null"),
+		}
+	}
+
+	/// Ajoute un nombre, ou `null`.
+	pub(crate) fn number(&mut self, key: &str, value: Option<impl std::fmt::Display>) -> &mut Self
+	{
+		match value {
+			| Some(value) => {
+				self.key(key);
+				_ = write!(self.buffer, "{value}");
+				self
+			}
+			| None => self.raw(key, "null"),
+		}
+	}
+
+	/// Ajoute une valeur JSON déjà encodée (objet, tableau, ...).
+	pub(crate) fn raw(&mut self, key: &str, json: &str) -> &mut Self
+	{
+		self.key(key);
+		self.buffer.push_str(json);
+		self
+	}
+
+	/// Termine l'objet.
+	pub(crate) fn finish(&mut self) -> String
+	{
+		let mut output = std::mem::take(&mut self.buffer);
+		output.push('}');
+		output
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerFormatter for LoggerJsonFormat
+{
+	fn format(&self, entry: &LogEntry, _: &mut Echo) -> String
+	{
+		let mut key_values = JsonObject::new();
+		for (key, value) in entry.key_values.iter() {
+			key_values.string(key, value);
+		}
+
+		let mut line = JsonObject::new()
+			.string("timestamp", &entry.timestamp.to_rfc3339())
+			.string("level", entry.level.as_str())
+			.string("target", &entry.target)
+			.optional_string("module", entry.module_path.as_deref())
+			.optional_string("file", entry.file.as_deref())
+			.number("line", entry.line)
+			.string("thread", &entry.thread)
+			.string("message", &console::strip_ansi_codes(&entry.message))
+			.raw("key_values", &key_values.finish())
+			.finish();
+
+		line.push('\n');
+		line
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Ajoute une chaîne de caractères JSON, échappée, au tampon.
+pub(crate) fn push_string(buffer: &mut String, value: &str)
+{
+	buffer.push('"');
+
+	for ch in value.chars() {
+		match ch {
+			| '"' => buffer.push_str("\\\""),
+			| '\\' => buffer.push_str("\\\\"),
+			| '\n' => buffer.push_str("\\n"),
+			| '\r' => buffer.push_str("\\r"),
+			| '\t' => buffer.push_str("\\t"),
+			| ch if ch.is_control() => _ = write!(buffer, "\\u{:04x}", ch as u32),
+			| ch => buffer.push(ch),
+		}
+	}
+
+	buffer.push('"');
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{push_string, LoggerJsonFormat};
+	use crate::{Echo, LogEntry};
+
+	fn string(value: &str) -> String
+	{
+		let mut buffer = String::new();
+		push_string(&mut buffer, value);
+		buffer
+	}
+
+	#[test]
+	fn strings_are_escaped()
+	{
+		assert_eq!(string("plain é"), r#""plain é""#);
+		assert_eq!(string(r#"say "hi" \o/"#), r#""say \"hi\" \\o/""#);
+		assert_eq!(string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+		assert_eq!(string("\0\u{1b}\u{7f}"), r#""\u0000\u001b\u007f""#);
+	}
+
+	#[test]
+	fn one_line_without_ansi_codes()
+	{
+		let mut entry = LogEntry::new(log::Level::Warn, "app", "\u{1b}[31mred\u{1b}[0m\nline");
+		entry.key_values.push(("user \"id\"".to_owned(), "4\\2".to_owned()));
+
+		let line = Echo::format(&entry, true, true, &LoggerJsonFormat).unwrap();
+
+		assert!(line.ends_with('\n'));
+		assert_eq!(line.matches('\n').count(), 1);
+		assert!(line.contains(r#""level":"WARN""#), "{line}");
+		assert!(line.contains(r#""message":"red\nline""#), "{line}");
+		assert!(line.contains(r#""module":null,"file":null,"line":null"#), "{line}");
+		assert!(line.contains(r#""key_values":{"user \"id\"":"4\\2"}"#), "{line}");
+	}
+}
@@ -0,0 +1,185 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Write;
+
+use super::LoggerFormatter;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Les clés des champs ajoutés par ce format.
+const RESERVED: [&str; 4] = ["time", "level", "target", "msg"];
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format logfmt : une suite de paires `clé=valeur` par log, sans aucun
+/// code ANSI.
+///
+/// Les champs sont ceux du format par défaut (`time`, lorsque le timestamp
+/// est activé, `level`, `target` et `msg`), suivis des paires clé-valeur du
+/// log. Les valeurs contenant des espaces, des `=`, des guillemets ou des
+/// caractères de contrôle sont entourées de guillemets et échappées.
+///
+/// Une clé identique à l'un de ces champs est préfixée par `_` (`_level`,
+/// `_msg`, ...), afin que la ligne ne contienne pas deux fois la même clé.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct LoggerLogfmtFormat;
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerFormatter for LoggerLogfmtFormat
+{
+	fn format(&self, entry: &LogEntry, echo: &mut Echo) -> String
+	{
+		let mut line = String::new();
+
+		if let Some(time) = echo.time() {
+			push_pair(&mut line, "time", &time.to_rfc3339());
+		}
+
+		push_pair(&mut line, "level", &entry.level.as_str().to_lowercase());
+		push_pair(&mut line, "target", &entry.target);
+		push_pair(&mut line, "msg", &console::strip_ansi_codes(&entry.message));
+
+		for (key, value) in entry.key_values.iter() {
+			if RESERVED.contains(&key.as_str()) {
+				push_pair(&mut line, &format!("_{key}"), value);
+			} else {
+				push_pair(&mut line, key, value);
+			}
+		}
+
+		line.push('\n');
+		line
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Ajoute une paire `clé=valeur` à la ligne.
+///
+/// Les caractères interdits dans une clé sont remplacés par `_`.
+fn push_pair(line: &mut String, key: &str, value: &str)
+{
+	if !line.is_empty() {
+		line.push(' ');
+	}
+
+	if key.is_empty() {
+		line.push('_');
+	}
+
+	for ch in key.chars() {
+		if ch == '=' || ch == '"' || ch.is_whitespace() || ch.is_control() {
+			line.push('_');
+		} else {
+			line.push(ch);
+		}
+	}
+
+	line.push('=');
+
+	let quoted = value.is_empty()
+		|| value
+			.chars()
+			.any(|ch| ch == '=' || ch == '"' || ch == '\\' || ch.is_whitespace() || ch.is_control());
+
+	if !quoted {
+		line.push_str(value);
+		return;
+	}
+
+	line.push('"');
+	for ch in value.chars() {
+		match ch {
+			| '"' => line.push_str("\\\""),
+			| '\\' => line.push_str("\\\\"),
+			| '\n' => line.push_str("\\n"),
+			| '\r' => line.push_str("\\r"),
+			| '\t' => line.push_str("\\t"),
+			| ch if ch.is_control() => _ = write!(line, "\\u{:04x}", ch as u32),
+			| ch => line.push(ch),
+		}
+	}
+	line.push('"');
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{push_pair, LoggerLogfmtFormat};
+	use crate::{Echo, LogEntry};
+
+	fn pair(key: &str, value: &str) -> String
+	{
+		let mut line = String::new();
+		push_pair(&mut line, key, value);
+		line
+	}
+
+	#[test]
+	fn values_are_quoted_when_needed()
+	{
+		assert_eq!(pair("user", "42"), "user=42");
+		assert_eq!(pair("user", ""), r#"user="""#);
+		assert_eq!(pair("msg", "hello world"), r#"msg="hello world""#);
+		assert_eq!(pair("expr", "a=b"), r#"expr="a=b""#);
+		assert_eq!(pair("quote", r#"say "hi""#), r#"quote="say \"hi\"""#);
+		assert_eq!(pair("path", r"C:\logs"), r#"path="C:\\logs""#);
+		assert_eq!(pair("multi", "a\nb\u{1b}"), r#"multi="a\nb\u001b""#);
+	}
+
+	#[test]
+	fn keys_are_sanitized()
+	{
+		assert_eq!(pair("user id", "1"), "user_id=1");
+		assert_eq!(pair("a=b\"c\n", "1"), "a_b_c_=1");
+		assert_eq!(pair("", "1"), "_=1");
+	}
+
+	#[test]
+	fn line_without_ansi_codes()
+	{
+		let mut entry = LogEntry::new(log::Level::Info, "app::http", "\u{1b}[1mGET\u{1b}[0m /");
+		entry.key_values.push(("status".to_owned(), "200".to_owned()));
+
+		let line = Echo::format(&entry, true, false, &LoggerLogfmtFormat).unwrap();
+
+		assert_eq!(line, "level=info target=app::http msg=\"GET /\" status=200\n");
+	}
+
+	#[test]
+	fn key_values_do_not_shadow_fields()
+	{
+		let mut entry = LogEntry::new(log::Level::Warn, "app", "hello");
+		for key in ["time", "level", "target", "msg", "user"] {
+			entry.key_values.push((key.to_owned(), "x".to_owned()));
+		}
+
+		let line = Echo::format(&entry, false, false, &LoggerLogfmtFormat).unwrap();
+
+		assert_eq!(
+			line,
+			"level=warn target=app msg=hello _time=x _level=x _target=x _msg=x user=x\n"
+		);
+	}
+}
@@ -0,0 +1,103 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod ecs;
+mod gelf;
+mod grid;
+mod json;
+mod logfmt;
+mod otlp;
+mod template;
+
+pub use self::ecs::LoggerEcsFormat;
+pub use self::gelf::LoggerGelfFormat;
+pub use self::grid::LoggerGridFormat;
+pub use self::json::LoggerJsonFormat;
+pub use self::logfmt::LoggerLogfmtFormat;
+pub use self::otlp::LoggerOtlpFormat;
+pub use self::template::{LoggerTemplateError, LoggerTemplateFormat};
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Format du texte d'un log.
+///
+/// Les closures `Fn(&LogEntry, &mut Echo) -> String` implémentent cette
+/// interface, ce qui leur permet de capturer leur propre configuration.
+pub trait LoggerFormatter
+	: Send
+	+ Sync
+{
+	/// Formate le log. Le texte retourné est écrit tel quel dans la
+	/// destination.
+	fn format(&self, entry: &LogEntry, echo: &mut Echo) -> String;
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<F> LoggerFormatter for F
+where
+	F: Send + Sync,
+	F: Fn(&LogEntry, &mut Echo) -> String,
+{
+	fn format(&self, entry: &LogEntry, echo: &mut Echo) -> String
+	{
+		self(entry, echo)
+	}
+}
+
+impl LoggerFormatter for Box<dyn LoggerFormatter>
+{
+	fn format(&self, entry: &LogEntry, echo: &mut Echo) -> String
+	{
+		(**self).format(entry, echo)
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Format du seul message du log, sans code ANSI, pour les destinations qui
+/// transmettent les autres données du log à part (syslog, journald).
+pub(crate) fn message_format(entry: &LogEntry, _: &mut Echo) -> String
+{
+	let mut text = console::strip_ansi_codes(&entry.message).into_owned();
+	text.push('\n');
+	text
+}
+
+/// La sévérité syslog (RFC 5424) correspondant au niveau du log.
+pub(crate) fn syslog_severity(level: log::Level) -> u8
+{
+	match level {
+		| log::Level::Error => 3,
+		| log::Level::Warn => 4,
+		| log::Level::Info => 6,
+		| log::Level::Debug | log::Level::Trace => 7,
+	}
+}
+
+/// Le nom d'hôte du système, ou `localhost` lorsqu'il est introuvable.
+pub(crate) fn hostname() -> String
+{
+	std::env::var("HOSTNAME")
+		.ok()
+		.or_else(|| std::fs::read_to_string("/proc/sys/kernel/hostname").ok())
+		.or_else(|| std::env::var("COMPUTERNAME").ok())
+		.map(|host| host.trim().to_owned())
+		.filter(|host| !host.is_empty())
+		.unwrap_or_else(|| String::from("localhost"))
+}
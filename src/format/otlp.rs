@@ -0,0 +1,216 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::json::JsonObject;
+use super::LoggerFormatter;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format OTLP/JSON : chaque log est une requête d'export OpenTelemetry
+/// (`ExportLogsServiceRequest`) sur une seule ligne, sans aucun code ANSI.
+///
+/// Le log suit le modèle de données des logs OpenTelemetry :
+///
+///   - `severityNumber` et `severityText` à partir du niveau ;
+///   - `body` à partir du message ;
+///   - `scope` à partir de la cible ;
+///   - `attributes` à partir du module, du fichier, de la ligne, du thread et
+///     des paires clé-valeur ;
+///   - `traceId` et `spanId` à partir des paires clé-valeur `trace_id` et
+///     `span_id`, lorsqu'elles sont présentes ;
+///   - `resource` à partir des attributs de ressource, dont `service.name`.
+///
+/// Un fichier écrit avec ce format peut être lu par le récepteur
+/// `otlpjsonfile` du collecteur ; voir [crate::LoggerOtlpTransport] pour
+/// l'envoyer directement à un collecteur.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct LoggerOtlpFormat
+{
+	resource: Vec<(String, String)>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerOtlpFormat
+{
+	/// Le nom du service, l'attribut de ressource `service.name`. Par défaut,
+	/// la variable d'environnement `OTEL_SERVICE_NAME` ou, à défaut, le nom de
+	/// l'exécutable.
+	pub fn with_service_name(self, name: impl ToString) -> Self
+	{
+		self.with_resource_attribute("service.name", name)
+	}
+
+	/// Ajoute ou remplace un attribut de ressource.
+	pub fn with_resource_attribute(mut self, key: impl ToString, value: impl ToString) -> Self
+	{
+		let key = key.to_string();
+		self.resource.retain(|(k, _)| *k != key);
+		self.resource.push((key, value.to_string()));
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LoggerOtlpFormat
+{
+	fn default() -> Self
+	{
+		let service_name = std::env::var("OTEL_SERVICE_NAME")
+			.ok()
+			.or_else(|| {
+				std::env::current_exe()
+					.ok()
+					.and_then(|path| path.file_stem().map(|name| name.to_string_lossy().into_owned()))
+			})
+			.unwrap_or_else(|| String::from("unknown_service"));
+
+		Self {
+			resource: vec![
+				(String::from("service.name"), service_name),
+				(String::from("telemetry.sdk.name"), String::from(env!("CARGO_PKG_NAME"))),
+				(String::from("telemetry.sdk.language"), String::from("rust")),
+				(String::from("telemetry.sdk.version"), String::from(env!("CARGO_PKG_VERSION"))),
+			],
+		}
+	}
+}
+
+impl LoggerFormatter for LoggerOtlpFormat
+{
+	fn format(&self, entry: &LogEntry, _: &mut Echo) -> String
+	{
+		let time = entry
+			.timestamp
+			.timestamp_nanos_opt()
+			.unwrap_or_default()
+			.to_string();
+
+		let mut attributes = vec![attribute_string("thread.name", &entry.thread)];
+		if let Some(module) = entry.module_path.as_deref() {
+			attributes.push(attribute_string("code.namespace", module));
+		}
+		if let Some(file) = entry.file.as_deref() {
+			attributes.push(attribute_string("code.filepath", file));
+		}
+		if let Some(line) = entry.line {
+			attributes.push(attribute("code.lineno", "intValue", &line.to_string()));
+		}
+
+		let mut trace_id = None;
+		let mut span_id = None;
+		for (key, value) in entry.key_values.iter() {
+			match key.as_str() {
+				| "trace_id" if is_hex_id(value, 32) => trace_id = Some(value.to_lowercase()),
+				| "span_id" if is_hex_id(value, 16) => span_id = Some(value.to_lowercase()),
+				| _ => attributes.push(attribute_string(key, value)),
+			}
+		}
+
+		let mut body = JsonObject::new();
+		body.string("stringValue", &console::strip_ansi_codes(&entry.message));
+
+		let mut record = JsonObject::new();
+		record
+			.string("timeUnixNano", &time)
+			.string("observedTimeUnixNano", &time)
+			.number("severityNumber", Some(severity_number(entry.level)))
+			.string("severityText", entry.level.as_str())
+			.raw("body", &body.finish())
+			.raw("attributes", &array(attributes));
+		if let Some(trace_id) = trace_id {
+			record.string("traceId", &trace_id);
+		}
+		if let Some(span_id) = span_id {
+			record.string("spanId", &span_id);
+		}
+
+		let mut scope = JsonObject::new();
+		scope.string("name", &entry.target);
+
+		let mut scope_logs = JsonObject::new();
+		scope_logs
+			.raw("scope", &scope.finish())
+			.raw("logRecords", &array([record.finish()]));
+
+		let mut resource = JsonObject::new();
+		resource.raw(
+			"attributes",
+			&array(self.resource.iter().map(|(key, value)| attribute_string(key, value))),
+		);
+
+		let mut resource_logs = JsonObject::new();
+		resource_logs
+			.raw("resource", &resource.finish())
+			.raw("scopeLogs", &array([scope_logs.finish()]));
+
+		let mut line = JsonObject::new()
+			.raw("resourceLogs", &array([resource_logs.finish()]))
+			.finish();
+		line.push('\n');
+		line
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Le `SeverityNumber` OpenTelemetry correspondant au niveau du log.
+fn severity_number(level: log::Level) -> u8
+{
+	match level {
+		| log::Level::Trace => 1,
+		| log::Level::Debug => 5,
+		| log::Level::Info => 9,
+		| log::Level::Warn => 13,
+		| log::Level::Error => 17,
+	}
+}
+
+/// Un identifiant de trace ou de span : `len` chiffres hexadécimaux, pas tous
+/// nuls.
+fn is_hex_id(value: &str, len: usize) -> bool
+{
+	value.len() == len && value.chars().all(|ch| ch.is_ascii_hexdigit()) && value.chars().any(|ch| ch != '0')
+}
+
+fn attribute_string(key: &str, value: &str) -> String
+{
+	attribute(key, "stringValue", value)
+}
+
+/// Un attribut OTLP (`KeyValue`), dont la valeur est encodée en chaîne.
+fn attribute(key: &str, kind: &str, value: &str) -> String
+{
+	let mut any_value = JsonObject::new();
+	any_value.string(kind, value);
+
+	JsonObject::new()
+		.string("key", key)
+		.raw("value", &any_value.finish())
+		.finish()
+}
+
+/// Un tableau JSON à partir de valeurs déjà encodées.
+fn array(values: impl IntoIterator<Item = String>) -> String
+{
+	format!("[{}]", values.into_iter().collect::<Vec<_>>().join(","))
+}
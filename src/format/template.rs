@@ -0,0 +1,474 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::{error, fmt, str};
+
+use console::style;
+
+use super::LoggerFormatter;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+use crate::layout;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Format des logs décrit par un template, analysé une seule fois à la
+/// construction.
+///
+/// Le template contient du texte et des champs entre accolades, optionnellement
+/// suivis d'une spécification après `:` :
+///
+///   - `{time}`, `{time:%H:%M:%S}` : la date du log, au format `strftime`,
+///     vide lorsque le timestamp est désactivé ;
+///   - `{level}`, `{target}`, `{module}`, `{file}`, `{line}`, `{thread}`,
+///     `{message}` : les données du log ;
+///   - `{kv}` : les paires clé-valeur du log, sous la forme `clé=valeur` ;
+///   - `{"texte"}` : un texte littéral, qui peut ainsi recevoir une
+///     spécification, par exemple `{"->":red}` ;
+///   - `{delimiter}` : sépare deux colonnes de la grille de l'[Echo]. Une
+///     colonne vide est retirée avec le délimiteur qui la suit.
+///
+/// La spécification des champs, hors `{time}`, est une liste séparée par des
+/// virgules d'alignements (`<10`, `>5`, `^8`) et de styles (`dim`, `bold`,
+/// `red`, `black.bright`, ...). Les styles ne sont appliqués que lorsque les
+/// couleurs sont activées ; sans style, le niveau a sa couleur habituelle.
+///
+/// Les accolades littérales s'écrivent `{{` et `}}`.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct LoggerTemplateFormat
+{
+	template: String,
+	columns: Vec<Vec<Segment>>,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+struct Spec
+{
+	alignment: Option<(Alignment, usize)>,
+	style: Option<console::Style>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Erreur d'analyse d'un template.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum LoggerTemplateError
+{
+	/// Une accolade ouvrante n'est jamais fermée.
+	UnclosedBrace(usize),
+	/// Une accolade fermante n'a pas d'accolade ouvrante.
+	UnexpectedBrace(usize),
+	/// Le champ n'existe pas.
+	UnknownField(String),
+	/// La spécification d'un champ est invalide.
+	InvalidSpec(String),
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+enum Segment
+{
+	Text(String),
+	Field(Field, Spec),
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+enum Field
+{
+	Time(String),
+	Level,
+	Target,
+	Module,
+	File,
+	Line,
+	Thread,
+	Message,
+	KeyValues,
+	Literal(String),
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+enum Alignment
+{
+	Left,
+	Right,
+	Center,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerTemplateFormat
+{
+	/// Le template par défaut des logs : le niveau, la date, la cible et le
+	/// message, alignés en colonnes.
+	pub const DEFAULT: &'static str =
+		"{level:>5} {delimiter} {time} {delimiter} {target:black.bright} {\"->\":red} {message}";
+
+	/// Analyse le template.
+	pub fn new(template: impl ToString) -> Result<Self, LoggerTemplateError>
+	{
+		let template = template.to_string();
+		let columns = parse(&template)?;
+		Ok(Self { template, columns })
+	}
+
+	/// Le template, tel qu'il a été donné.
+	pub fn template(&self) -> &str
+	{
+		&self.template
+	}
+}
+
+impl Field
+{
+	fn parse(name: &str, spec: Option<&str>) -> Result<(Self, Spec), LoggerTemplateError>
+	{
+		let field = match name {
+			| "time" => {
+				let format = spec.unwrap_or("%Y-%m-%d@%H:%M:%S");
+				let invalid = chrono::format::StrftimeItems::new(format)
+					.any(|item| matches!(item, chrono::format::Item::Error));
+				if invalid {
+					return Err(LoggerTemplateError::InvalidSpec(format.to_owned()));
+				}
+				return Ok((Self::Time(format.to_owned()), Spec::default()));
+			}
+			| "level" => Self::Level,
+			| "target" => Self::Target,
+			| "module" => Self::Module,
+			| "file" => Self::File,
+			| "line" => Self::Line,
+			| "thread" => Self::Thread,
+			| "message" => Self::Message,
+			| "kv" => Self::KeyValues,
+			| _ => return Err(LoggerTemplateError::UnknownField(name.to_owned())),
+		};
+
+		let spec = match spec {
+			| Some(spec) => Spec::parse(spec)?,
+			| None => Spec::default(),
+		};
+
+		Ok((field, spec))
+	}
+
+	/// La valeur brute du champ, sans alignement ni style.
+	fn value(&self, entry: &LogEntry, echo: &Echo) -> String
+	{
+		match self {
+			| Self::Time(format) => echo
+				.time()
+				.map(|time| time.format(format).to_string())
+				.unwrap_or_default(),
+			| Self::Level => entry.level.to_string(),
+			| Self::Target => entry.target.clone(),
+			| Self::Module => entry.module_path.clone().unwrap_or_default(),
+			| Self::File => entry.file.clone().unwrap_or_default(),
+			| Self::Line => entry.line.map(|line| line.to_string()).unwrap_or_default(),
+			| Self::Thread => entry.thread.clone(),
+			| Self::Message => entry.message.clone(),
+			| Self::Literal(text) => text.clone(),
+			| Self::KeyValues => entry
+				.key_values
+				.iter()
+				.map(|(key, value)| format!("{key}={value}"))
+				.collect::<Vec<_>>()
+				.join(" "),
+		}
+	}
+}
+
+impl Spec
+{
+	const STYLES: [&'static str; 16] = [
+		"black", "red", "green", "yellow", "blue", "magenta", "cyan", "white", "bright", "bold",
+		"dim", "italic", "underlined", "blink", "reverse", "hidden",
+	];
+
+	fn parse(spec: &str) -> Result<Self, LoggerTemplateError>
+	{
+		let invalid = || LoggerTemplateError::InvalidSpec(spec.to_owned());
+		let mut output = Self::default();
+
+		for item in spec.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+			let alignment = match item.as_bytes()[0] {
+				| b'<' => Some(Alignment::Left),
+				| b'>' => Some(Alignment::Right),
+				| b'^' => Some(Alignment::Center),
+				| _ => None,
+			};
+
+			if let Some(alignment) = alignment {
+				let width = item[1..].parse().map_err(|_| invalid())?;
+				output.alignment.replace((alignment, width));
+				continue;
+			}
+
+			let known = item.split('.').all(|part| {
+				let color = part.strip_prefix("on_").unwrap_or(part);
+				Self::STYLES.contains(&part) || Self::STYLES[..8].contains(&color)
+			});
+			if !known {
+				return Err(invalid());
+			}
+
+			output.style.replace(console::Style::from_dotted_str(item));
+		}
+
+		Ok(output)
+	}
+
+	fn apply(&self, value: String, level: Option<log::Level>, colorized: bool) -> String
+	{
+		let value = match self.alignment {
+			| Some((Alignment::Left, width)) => format!("{value:<width$}"),
+			| Some((Alignment::Right, width)) => format!("{value:>width$}"),
+			| Some((Alignment::Center, width)) => format!("{value:^width$}"),
+			| None => value,
+		};
+
+		if !colorized {
+			return value;
+		}
+
+		match (&self.style, level) {
+			| (Some(style), _) => style.apply_to(value).to_string(),
+			| (None, Some(log::Level::Error)) => style(value).red().to_string(),
+			| (None, Some(log::Level::Warn)) => style(value).yellow().to_string(),
+			| (None, Some(log::Level::Info)) => style(value).blue().to_string(),
+			| (None, Some(log::Level::Debug)) => style(value).magenta().to_string(),
+			| (None, Some(log::Level::Trace)) => style(value).white().to_string(),
+			| (None, None) => value,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LoggerTemplateFormat
+{
+	fn default() -> Self
+	{
+		Self::new(Self::DEFAULT).expect("Le template par défaut")
+	}
+}
+
+impl str::FromStr for LoggerTemplateFormat
+{
+	type Err = LoggerTemplateError;
+
+	fn from_str(template: &str) -> Result<Self, Self::Err>
+	{
+		Self::new(template)
+	}
+}
+
+impl LoggerFormatter for LoggerTemplateFormat
+{
+	fn format(&self, entry: &LogEntry, echo: &mut Echo) -> String
+	{
+		let columns: Vec<String> = self
+			.columns
+			.iter()
+			.map(|segments| {
+				segments
+					.iter()
+					.map(|segment| {
+						match segment {
+							| Segment::Text(text) => text.clone(),
+							| Segment::Field(field, spec) => {
+								let level = matches!(field, Field::Level).then_some(entry.level);
+								spec.apply(field.value(entry, echo), level, echo.is_colorized())
+							}
+						}
+					})
+					.collect::<String>()
+					.trim()
+					.to_owned()
+			})
+			.collect();
+
+		let last = columns.len() - 1;
+		let mut cells = Vec::with_capacity(columns.len() * 2);
+
+		for (idx, column) in columns.into_iter().enumerate() {
+			if column.is_empty() && idx != last {
+				continue;
+			}
+			if !cells.is_empty() {
+				cells.push(layout::Cell::new(echo.delimiter()));
+			}
+			cells.push(layout::Cell::new(column));
+		}
+
+		echo.table().add_line(cells);
+		echo.table().render()
+	}
+}
+
+impl fmt::Display for LoggerTemplateError
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+	{
+		match self {
+			| Self::UnclosedBrace(pos) => write!(f, "accolade non fermée (position {pos})"),
+			| Self::UnexpectedBrace(pos) => write!(f, "accolade fermante inattendue (position {pos})"),
+			| Self::UnknownField(name) => write!(f, "champ inconnu: `{name}`"),
+			| Self::InvalidSpec(spec) => write!(f, "spécification invalide: `{spec}`"),
+		}
+	}
+}
+
+impl error::Error for LoggerTemplateError {}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Analyse le template en colonnes, séparées par `{delimiter}`.
+fn parse(template: &str) -> Result<Vec<Vec<Segment>>, LoggerTemplateError>
+{
+	let mut columns = vec![Vec::new()];
+	let mut text = String::new();
+	let mut chars = template.char_indices().peekable();
+
+	while let Some((pos, ch)) = chars.next() {
+		match ch {
+			| '{' if chars.next_if(|(_, next)| *next == '{').is_some() => text.push('{'),
+			| '}' if chars.next_if(|(_, next)| *next == '}').is_some() => text.push('}'),
+			| '}' => return Err(LoggerTemplateError::UnexpectedBrace(pos)),
+			| '{' => {
+				// NOTE: un texte littéral peut contenir des accolades.
+				let mut inner = String::new();
+				let mut quoted = false;
+				loop {
+					match chars.next() {
+						| Some((_, '}')) if !quoted => break,
+						| Some((_, ch)) => {
+							quoted ^= ch == '"';
+							inner.push(ch);
+						}
+						| None => return Err(LoggerTemplateError::UnclosedBrace(pos)),
+					}
+				}
+
+				let column = columns.last_mut().expect("colonne courante");
+				if !text.is_empty() {
+					column.push(Segment::Text(std::mem::take(&mut text)));
+				}
+
+				if let Some(literal) = inner.trim_start().strip_prefix('"') {
+					let (literal, rest) = literal.split_once('"').expect("texte littéral fermé");
+					let spec = match rest.trim_start() {
+						| "" => Spec::default(),
+						| rest => match rest.strip_prefix(':') {
+							| Some(spec) => Spec::parse(spec)?,
+							| None => return Err(LoggerTemplateError::UnknownField(inner)),
+						},
+					};
+					column.push(Segment::Field(Field::Literal(literal.to_owned()), spec));
+					continue;
+				}
+
+				let (name, spec) = match inner.split_once(':') {
+					| Some((name, spec)) => (name.trim(), Some(spec)),
+					| None => (inner.trim(), None),
+				};
+
+				if name == "delimiter" {
+					columns.push(Vec::new());
+					continue;
+				}
+
+				let (field, spec) = Field::parse(name, spec)?;
+				column.push(Segment::Field(field, spec));
+			}
+			| _ => text.push(ch),
+		}
+	}
+
+	if !text.is_empty() {
+		columns.last_mut().expect("colonne courante").push(Segment::Text(text));
+	}
+
+	Ok(columns)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{LoggerTemplateError, LoggerTemplateFormat};
+	use crate::{Echo, LogEntry};
+
+	fn render(template: &str, colorized: bool) -> String
+	{
+		// NOTE: les tests ne sont pas lancés dans un terminal.
+		console::set_colors_enabled(true);
+
+		let format = LoggerTemplateFormat::new(template).unwrap();
+		let mut entry = LogEntry::new(log::Level::Info, "app::http", "hello");
+		entry.key_values.push(("user".to_owned(), "42".to_owned()));
+
+		let text = Echo::format(&entry, colorized, false, &format).unwrap();
+		Echo::strip_nul(&text).into_owned()
+	}
+
+	#[test]
+	fn parse_errors()
+	{
+		let error = |template: &str| LoggerTemplateFormat::new(template).unwrap_err();
+
+		assert_eq!(error("{message"), LoggerTemplateError::UnclosedBrace(0));
+		assert_eq!(error("a } b"), LoggerTemplateError::UnexpectedBrace(2));
+		assert_eq!(error("{nope}"), LoggerTemplateError::UnknownField("nope".to_owned()));
+		assert_eq!(error("{level:<x}"), LoggerTemplateError::InvalidSpec("<x".to_owned()));
+		assert_eq!(error("{level:sparkly}"), LoggerTemplateError::InvalidSpec("sparkly".to_owned()));
+		assert_eq!(error("{time:%Q}"), LoggerTemplateError::InvalidSpec("%Q".to_owned()));
+		assert_eq!(error("{\"->\" red}"), LoggerTemplateError::UnknownField("\"->\" red".to_owned()));
+		assert_eq!(error("{\"->}"), LoggerTemplateError::UnclosedBrace(0));
+	}
+
+	#[test]
+	fn literal_fields()
+	{
+		assert_eq!(render("{target} {\"->\"} {message}", false).trim(), "app::http -> hello");
+		assert_eq!(render("{message} {\"{}\":>4}", false).trim(), "hello   {}");
+
+		let colored = render("{\"->\":red}", true);
+		assert_eq!(colored.trim(), console::style("->").red().force_styling(true).to_string());
+	}
+
+	#[test]
+	fn default_template_styles_the_arrow()
+	{
+		let plain = render(LoggerTemplateFormat::DEFAULT, false);
+		assert!(plain.contains(" INFO"), "{plain:?}");
+		assert!(plain.contains("app::http -> hello"), "{plain:?}");
+
+		let colored = render(LoggerTemplateFormat::DEFAULT, true);
+		let arrow = console::style("->").red().force_styling(true).to_string();
+		assert!(colored.contains(&arrow), "{colored:?}");
+	}
+}
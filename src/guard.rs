@@ -0,0 +1,122 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Garde du logger global, retournée par `try_initialize()`.
+///
+/// Lorsqu'elle est détruite, la garde vide le logger global, puis arrête les
+/// threads d'écriture du mode asynchrone après qu'ils aient écrit les logs
+/// restants. Les logs suivants sont écrits directement, sans thread.
+///
+/// La garde doit donc vivre jusqu'à la fin du programme, par exemple avec
+/// `let _guard = logger.try_initialize()?;` au début de `main`.
+#[must_use = "les logs en attente sont écrits lorsque la garde est détruite"]
+pub struct LoggerGuard
+{
+	_private: (),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerGuard
+{
+	pub(crate) fn new() -> Self
+	{
+		Self { _private: () }
+	}
+
+	/// Vide le logger global, en attendant que les logs en attente du mode
+	/// asynchrone soient écrits.
+	pub fn flush(&self)
+	{
+		crate::sink::flush();
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Drop for LoggerGuard
+{
+	fn drop(&mut self)
+	{
+		crate::sink::flush();
+		crate::asynchronous::shutdown();
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io;
+	use std::sync::{Arc, Mutex};
+	use std::time::Duration;
+
+	use crate::{LogEntry, Logger, LoggerAsync, LoggerBackendBuilderExtension, LoggerBuilder, LoggerSink};
+
+	/// Destination lente, qui conserve le texte des logs reçus.
+	#[derive(Clone)]
+	#[derive(Default)]
+	struct SlowSink(Arc<Mutex<Vec<String>>>);
+
+	impl LoggerSink for SlowSink
+	{
+		fn write(&self, _: &LogEntry, text: &str) -> io::Result<()>
+		{
+			std::thread::sleep(Duration::from_millis(20));
+			self.0.lock().unwrap().push(text.to_owned());
+			Ok(())
+		}
+	}
+
+	// NOTE: le seul test qui définit le logger global, celui-ci ne pouvant
+	// être défini qu'une fois par processus.
+	#[test]
+	fn dropped_guard_drains_async_sinks()
+	{
+		let sink = SlowSink::default();
+
+		let builder = Logger::sink(sink.clone())
+			.with_color(false)
+			.with_timestamp(false)
+			.with_async(LoggerAsync::new(64));
+		#[cfg(not(feature = "tracing"))]
+		let builder = builder
+			.with_level(log::LevelFilter::Trace)
+			.with_format(|entry: &LogEntry, _: &mut crate::Echo| format!("{}\n", entry.message));
+		#[cfg(feature = "tracing")]
+		let builder = builder.with_level(tracing::level_filters::LevelFilter::TRACE);
+
+		let guard = builder.try_initialize().expect("le premier logger global");
+
+		for idx in 0..5 {
+			#[cfg(not(feature = "tracing"))]
+			log::info!("log {idx}");
+			#[cfg(feature = "tracing")]
+			tracing::info!("log {idx}");
+		}
+
+		drop(guard);
+
+		let written = sink.0.lock().unwrap().clone();
+		assert_eq!(written.len(), 5);
+		for (idx, text) in written.iter().enumerate() {
+			assert!(text.contains(&format!("log {idx}")), "{text}");
+		}
+
+		assert!(Logger::sink(SlowSink::default()).try_initialize().is_err());
+	}
+}
@@ -28,36 +28,126 @@ impl LoggerInitiator
 	{
 		let stdout = builder.build();
 		let level = stdout.level();
+		Self::install(stdout, level)
+	}
 
+	#[cfg(feature = "tracing")]
+	pub fn stdout(builder: impl LoggerBuilder<crate::LoggerStdout>) -> Result<(), &'static str>
+	{
+		Self::install_tracing(builder.build().backend)
+	}
+
+	/// Initialise le logger FILE à partir du builder.
+	#[cfg(not(feature = "tracing"))]
+	pub fn file(builder: impl LoggerBuilder<crate::LoggerFile>) -> Result<(), log::SetLoggerError>
+	{
+		let file = builder.build();
+		let level = file.level();
+		Self::install(file, level)
+	}
+
+	#[cfg(feature = "tracing")]
+	pub fn file(builder: impl LoggerBuilder<crate::LoggerFile>) -> Result<(), &'static str>
+	{
+		Self::install_tracing(builder.build().backend)
+	}
+
+	/// Initialise le logger WRITER à partir du builder.
+	#[cfg(not(feature = "tracing"))]
+	pub fn writer(builder: impl LoggerBuilder<crate::LoggerWriter>) -> Result<(), log::SetLoggerError>
+	{
+		let writer = builder.build();
+		let level = writer.level();
+		Self::install(writer, level)
+	}
+
+	#[cfg(feature = "tracing")]
+	pub fn writer(builder: impl LoggerBuilder<crate::LoggerWriter>) -> Result<(), &'static str>
+	{
+		Self::install_tracing(builder.build().backend)
+	}
+
+	/// Initialise un logger écrivant dans une destination utilisateur à
+	/// partir du builder.
+	#[cfg(not(feature = "tracing"))]
+	pub fn backend(builder: impl LoggerBuilder<crate::LoggerBackend>) -> Result<(), log::SetLoggerError>
+	{
+		let backend = builder.build();
+		let level = backend.level();
+		Self::install(backend, level)
+	}
+
+	#[cfg(feature = "tracing")]
+	pub fn backend(builder: impl LoggerBuilder<crate::LoggerBackend>) -> Result<(), &'static str>
+	{
+		Self::install_tracing(builder.build())
+	}
+
+	/// Initialise le logger MULTI à partir du builder.
+	///
+	/// NOTE: indisponible avec la fonctionnalité `tracing` (voir
+	/// [crate::Logger::multi]).
+	#[cfg(not(feature = "tracing"))]
+	pub fn multi(builder: impl Into<crate::LoggerMultiBuilder>) -> Result<(), log::SetLoggerError>
+	{
+		let multi = builder.into().build();
+		let level = multi.level();
+		Self::install(multi, level)
+	}
+
+	// NOTE: Initialiser d'autres types de logger ici...
+}
+
+impl LoggerInitiator
+{
+	/// Définit le logger global du système de log.
+	#[cfg(not(feature = "tracing"))]
+	fn install(logger: impl log::Log + 'static, level: log::LevelFilter) -> Result<(), log::SetLoggerError>
+	{
 		log::set_max_level(level);
 
 		if log::LevelFilter::Off == log::max_level() {
 			const NO: crate::noop::NopeLogger = crate::noop::NopeLogger;
 			log::set_logger(&NO)
 		} else {
-			log::set_boxed_logger(Box::new(stdout))
+			log::set_boxed_logger(Box::new(logger))
 		}
 	}
 
+	/// Définit le subscriber global de `tracing`, qui écrit dans la
+	/// destination du logger.
 	#[cfg(feature = "tracing")]
-	pub fn stdout(builder: impl LoggerBuilder<crate::LoggerStdout>) -> Result<(), &'static str>
+	fn install_tracing(backend: crate::LoggerBackend) -> Result<(), &'static str>
 	{
-		let stdout = builder.build();
-		let level = stdout.level();
+		let crate::LoggerBackend {
+			level,
+			colorized,
+			timestamp,
+			sink,
+			processors,
+			..
+		} = backend;
+
+		let sink: std::sync::Arc<dyn crate::sink::LoggerSink> = sink.into();
 
 		let trsb = tracing_subscriber::fmt()
 			.with_max_level(level)
-			.with_ansi(stdout.colorized)
-			.with_line_number(true);
+			.with_ansi(colorized)
+			.with_line_number(true)
+			.with_writer(crate::sink::TracingSink {
+				sink: sink.clone(),
+				processors,
+			});
 
-		if stdout.timestamp {
-			trsb.init();
+		let installed = if timestamp {
+			trsb.try_init()
 		} else {
-			trsb.without_time().init();
-		}
+			trsb.without_time().try_init()
+		};
+		installed.map_err(|_| "Un subscriber global de `tracing` est déjà défini")?;
+
+		_ = crate::sink::TRACING_SINK.set(sink);
 
 		Ok(())
 	}
-
-	// NOTE: Initialiser d'autres types de logger ici...
 }
@@ -52,7 +52,7 @@ impl<'d> GridLayout<'d>
 			style: STYLE_BLANK,
 
 			widths: HashMap::new(),
-			max_width: std::usize::MAX,
+			max_width: usize::MAX,
 
 			separate_rows: true,
 
@@ -110,7 +110,7 @@ impl<'d> GridLayout<'d>
 				Self::add_newline_to_buffer(&mut print_buffer, separator);
 			}
 
-			Self::add_newline_to_buffer(&mut print_buffer, &self.rows[index].format(&max_widths, &self.style));
+			Self::add_newline_to_buffer(&mut print_buffer, self.rows[index].format(&max_widths, &self.style));
 		}
 
 		if self.boarder.bottom {
@@ -206,10 +206,10 @@ impl<'d> Default for GridLayout<'d>
 	}
 }
 
-impl<'d> ToString for GridLayout<'d>
+impl<'d> std::fmt::Display for GridLayout<'d>
 {
-	fn to_string(&self) -> String
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
 	{
-		self.render()
+		write!(f, "{}", self.render())
 	}
 }
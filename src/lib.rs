@@ -8,19 +8,50 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod asynchronous;
+mod backend;
 pub(crate) mod builder;
 mod initiator;
 mod echo;
+mod entry;
+mod file;
+mod filter;
+mod format;
+mod guard;
 pub mod layout;
+#[cfg(not(feature = "tracing"))]
+mod multi;
+#[cfg(not(feature = "tracing"))]
 mod noop;
+mod processor;
 #[cfg(feature = "serde")]
 mod settings;
+mod sink;
 mod stdout;
+mod transport;
+mod writer;
 
 pub use log::*;
 
+pub use self::asynchronous::{LoggerAsync, LoggerBackpressure};
+pub use self::backend::*;
 pub use self::builder::{Logger, LoggerBuilder};
+pub use self::echo::Echo;
+pub use self::entry::LogEntry;
+pub use self::file::*;
+pub use self::filter::LoggerFilter;
+pub use self::format::{
+	LoggerEcsFormat, LoggerFormatter, LoggerGelfFormat, LoggerGridFormat, LoggerJsonFormat,
+	LoggerLogfmtFormat, LoggerOtlpFormat, LoggerTemplateError, LoggerTemplateFormat,
+};
+pub use self::guard::LoggerGuard;
 pub use self::initiator::LoggerInitiator;
+#[cfg(not(feature = "tracing"))]
+pub use self::multi::*;
+pub use self::processor::LoggerProcessor;
 #[cfg(feature = "serde")]
-pub use self::settings::{Settings, SettingsLevel};
+pub use self::settings::{Settings, SettingsFormat, SettingsLevel, SettingsRotation, SettingsTimezone};
+pub use self::sink::LoggerSink;
 pub use self::stdout::*;
+pub use self::transport::*;
+pub use self::writer::*;
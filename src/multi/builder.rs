@@ -0,0 +1,124 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use super::extension::LoggerMultiBuilderExtension;
+use super::{LoggerMultiBackend, LoggerMultiDispatch, LoggerMultiEntry};
+use crate::processor::LoggerProcessors;
+use crate::{LoggerBuilder, LoggerFilter, LoggerMulti, LoggerProcessor, LoggerRoute};
+
+// --------- //
+// Structure //
+// --------- //
+
+#[derive(Default)]
+pub struct LoggerMultiBuilder
+{
+	level: Option<log::LevelFilter>,
+	filter: LoggerFilter,
+	processors: LoggerProcessors,
+	loggers: Vec<LoggerMultiEntry>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerMultiBuilder
+{
+	/// Ajoute un logger qui reçoit tous les logs, construit à partir de son
+	/// builder.
+	///
+	/// Le niveau et les filtres du builder ne s'appliquent qu'à ce logger.
+	pub fn with_sink<T>(self, builder: impl LoggerBuilder<T>) -> Self
+	where
+		T: LoggerMultiBackend,
+	{
+		self.push(LoggerMultiDispatch::Always, builder)
+	}
+
+	/// Ajoute un logger qui ne reçoit que les logs correspondant à la route.
+	/// Un log peut correspondre à plusieurs routes : il est alors distribué
+	/// à chacun de leurs loggers.
+	pub fn with_route<T>(self, route: LoggerRoute, builder: impl LoggerBuilder<T>) -> Self
+	where
+		T: LoggerMultiBackend,
+	{
+		self.push(LoggerMultiDispatch::Route(route), builder)
+	}
+
+	/// Ajoute un logger qui ne reçoit que les logs ne correspondant à aucune
+	/// route.
+	pub fn with_fallback<T>(self, builder: impl LoggerBuilder<T>) -> Self
+	where
+		T: LoggerMultiBackend,
+	{
+		self.push(LoggerMultiDispatch::Fallback, builder)
+	}
+
+	fn push<T>(mut self, dispatch: LoggerMultiDispatch, builder: impl LoggerBuilder<T>) -> Self
+	where
+		T: LoggerMultiBackend,
+	{
+		self.loggers.push(LoggerMultiEntry {
+			dispatch,
+			logger: Box::new(builder.build()),
+		});
+		self
+	}
+
+	/// Ajoute un filtre global, appliqué avant ceux des loggers.
+	pub fn filter<F>(mut self, predicate: F, dependency: impl ToString) -> Self
+	where
+		F: 'static,
+		F: Send + Sync,
+		F: Fn(&log::Metadata) -> bool,
+	{
+		self.filter.push_callback(predicate);
+		self.filter.add_dependency(dependency);
+		self
+	}
+
+	/// Ajoute un processeur global, appliqué avant la distribution du log
+	/// aux loggers, et donc avant leurs propres processeurs.
+	pub fn with_processor(mut self, processor: impl LoggerProcessor + 'static) -> Self
+	{
+		self.processors.push(processor);
+		self
+	}
+
+	/// Le niveau global des logs. Par défaut, il s'agit du niveau le plus
+	/// élevé parmi ceux des loggers.
+	pub fn with_level(mut self, level: impl Into<log::LevelFilter>) -> Self
+	{
+		self.level.replace(level.into());
+		self
+	}
+
+	/// Construction du logger.
+	pub fn build(self) -> LoggerMulti
+	{
+		let level = self.level.unwrap_or_else(|| {
+			self.loggers
+				.iter()
+				.map(|entry| entry.logger.level())
+				.max()
+				.unwrap_or(log::LevelFilter::Off)
+		});
+
+		LoggerMulti {
+			level,
+			filter: self.filter,
+			processors: self.processors,
+			loggers: self.loggers,
+		}
+	}
+}
+
+impl LoggerMultiBuilderExtension for LoggerMultiBuilder {}
@@ -0,0 +1,33 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::initiator::LoggerInitiator;
+use crate::{LoggerGuard, LoggerMultiBuilder};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait LoggerMultiBuilderExtension
+	: Sized
+	+ Into<LoggerMultiBuilder>
+{
+	fn initialize(self)
+	{
+		LoggerInitiator::multi(self).expect("L'initialisation du logger (multi)");
+	}
+
+	/// Initialise le logger et retourne une garde qui écrit les logs en
+	/// attente lorsqu'elle est détruite.
+	fn try_initialize(self) -> Result<LoggerGuard, log::SetLoggerError>
+	{
+		LoggerInitiator::multi(self).map(|()| LoggerGuard::new())
+	}
+}
@@ -0,0 +1,350 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod builder;
+mod extension;
+mod route;
+
+pub use self::builder::LoggerMultiBuilder;
+pub use self::extension::LoggerMultiBuilderExtension;
+pub use self::route::LoggerRoute;
+use crate::entry::LogEntry;
+use crate::processor::LoggerProcessors;
+use crate::{LoggerBackend, LoggerFile, LoggerFilter, LoggerStdout, LoggerWriter};
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Logger pouvant recevoir les logs d'un [LoggerMulti].
+pub trait LoggerMultiBackend
+	: log::Log
+	+ 'static
+{
+	/// Le niveau maximal des logs acceptés par ce logger.
+	fn level(&self) -> log::LevelFilter;
+
+	/// Écrit un log déjà accepté par [log::Log::enabled()], après lui avoir
+	/// appliqué les processeurs de ce logger.
+	fn log_entry(&self, entry: LogEntry);
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Logger qui distribue chaque log à plusieurs loggers.
+///
+/// Chaque logger garde son propre niveau, son propre filtre, son propre
+/// format et sa propre destination. Les routes décident en plus des loggers
+/// qui reçoivent un log donné.
+pub struct LoggerMulti
+{
+	pub(crate) level: log::LevelFilter,
+	pub(crate) filter: LoggerFilter,
+	pub(crate) processors: LoggerProcessors,
+	pub(crate) loggers: Vec<LoggerMultiEntry>,
+}
+
+/// Un logger d'un [LoggerMulti] et la manière de lui distribuer les logs.
+pub(crate) struct LoggerMultiEntry
+{
+	pub(crate) dispatch: LoggerMultiDispatch,
+	pub(crate) logger: Box<dyn LoggerMultiBackend>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+pub(crate) enum LoggerMultiDispatch
+{
+	/// Le logger reçoit tous les logs.
+	Always,
+	/// Le logger reçoit les logs correspondant à la route.
+	Route(LoggerRoute),
+	/// Le logger reçoit les logs ne correspondant à aucune route.
+	Fallback,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerMulti
+{
+	pub fn builder() -> builder::LoggerMultiBuilder
+	{
+		builder::LoggerMultiBuilder::default()
+	}
+}
+
+impl LoggerMulti
+{
+	pub fn level(&self) -> log::LevelFilter
+	{
+		self.level
+	}
+
+	/// Les loggers auxquels le log doit être distribué.
+	fn dispatch<'a>(
+		&'a self,
+		metadata: &'a log::Metadata,
+	) -> impl Iterator<Item = &'a dyn LoggerMultiBackend>
+	{
+		let routed = self.loggers.iter().any(|entry| {
+			matches!(&entry.dispatch, LoggerMultiDispatch::Route(route) if route.matches(metadata))
+		});
+
+		self.loggers
+			.iter()
+			.filter(move |entry| {
+				match &entry.dispatch {
+					| LoggerMultiDispatch::Always => true,
+					| LoggerMultiDispatch::Route(route) => route.matches(metadata),
+					| LoggerMultiDispatch::Fallback => !routed,
+				}
+			})
+			.map(|entry| entry.logger.as_ref())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl log::Log for LoggerMulti
+{
+	/// Un log est accepté s'il passe le niveau et le filtre globaux, ainsi
+	/// que ceux d'au moins un des loggers vers lesquels il est routé.
+	///
+	/// Les processeurs globaux pouvant modifier la cible ou le niveau du log,
+	/// les routes et les loggers ne sont alors vérifiés qu'après leur
+	/// application.
+	fn enabled(&self, metadata: &log::Metadata) -> bool
+	{
+		metadata.level() <= self.level
+			&& self.filter.is_enabled(metadata)
+			&& (!self.processors.is_empty() || self.dispatch(metadata).any(|logger| logger.enabled(metadata)))
+	}
+
+	/// Distribue le log à chacun des loggers vers lesquels il est routé.
+	fn log(&self, record: &log::Record)
+	{
+		if !self.enabled(record.metadata()) {
+			return;
+		}
+
+		self.log_entry(LogEntry::from(record));
+	}
+
+	fn flush(&self)
+	{
+		for entry in self.loggers.iter() {
+			entry.logger.flush();
+		}
+	}
+}
+
+impl LoggerMultiBackend for LoggerMulti
+{
+	fn level(&self) -> log::LevelFilter
+	{
+		self.level
+	}
+
+	/// Les routes et les filtres des loggers s'appliquent au log tel que
+	/// modifié par les processeurs du logger multi.
+	fn log_entry(&self, entry: LogEntry)
+	{
+		let Some(entry) = self.processors.process(entry) else {
+			return;
+		};
+
+		let metadata = log::Metadata::builder()
+			.level(entry.level)
+			.target(&entry.target)
+			.build();
+
+		for logger in self.dispatch(&metadata) {
+			if logger.enabled(&metadata) {
+				logger.log_entry(entry.clone());
+			}
+		}
+	}
+}
+
+impl LoggerMultiBackend for LoggerStdout
+{
+	fn level(&self) -> log::LevelFilter
+	{
+		self.backend.level
+	}
+
+	fn log_entry(&self, entry: LogEntry)
+	{
+		self.backend.write_entry(entry);
+	}
+}
+
+impl LoggerMultiBackend for LoggerFile
+{
+	fn level(&self) -> log::LevelFilter
+	{
+		self.backend.level
+	}
+
+	fn log_entry(&self, entry: LogEntry)
+	{
+		self.backend.write_entry(entry);
+	}
+}
+
+impl LoggerMultiBackend for LoggerWriter
+{
+	fn level(&self) -> log::LevelFilter
+	{
+		self.backend.level
+	}
+
+	fn log_entry(&self, entry: LogEntry)
+	{
+		self.backend.write_entry(entry);
+	}
+}
+
+impl LoggerMultiBackend for LoggerBackend
+{
+	fn level(&self) -> log::LevelFilter
+	{
+		self.level
+	}
+
+	fn log_entry(&self, entry: LogEntry)
+	{
+		self.write_entry(entry);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io;
+	use std::sync::{Arc, Mutex};
+
+	use log::Log;
+
+	use super::LoggerMulti;
+	use crate::{Echo, LogEntry, LoggerBackend, LoggerBackendBuilder, LoggerBuilder, LoggerRoute, LoggerSink};
+
+	/// Destination qui conserve les messages reçus.
+	#[derive(Clone)]
+	#[derive(Default)]
+	struct Capture(Arc<Mutex<Vec<String>>>);
+
+	impl LoggerSink for Capture
+	{
+		fn write(&self, entry: &LogEntry, _: &str) -> io::Result<()>
+		{
+			self.0.lock().unwrap().push(entry.message.clone());
+			Ok(())
+		}
+	}
+
+	impl Capture
+	{
+		fn builder(&self, level: log::LevelFilter) -> LoggerBackendBuilder
+		{
+			LoggerBackend::builder(self.clone())
+				.with_level(level)
+				.with_format(|entry: &LogEntry, _: &mut Echo| entry.message.clone())
+		}
+
+		fn take(&self) -> Vec<String>
+		{
+			std::mem::take(&mut *self.0.lock().unwrap())
+		}
+	}
+
+	fn log(multi: &LoggerMulti, level: log::Level, target: &str, message: &str)
+	{
+		multi.log(
+			&log::Record::builder()
+				.args(format_args!("{message}"))
+				.level(level)
+				.target(target)
+				.build(),
+		);
+	}
+
+	fn matches(route: &LoggerRoute, level: log::Level, target: &str) -> bool
+	{
+		route.matches(&log::Metadata::builder().level(level).target(target).build())
+	}
+
+	#[test]
+	fn route_matches_level_and_target_prefix()
+	{
+		let route = LoggerRoute::target("app::db").with_target("sqlx").with_level(log::Level::Warn);
+
+		assert!(matches(&route, log::Level::Error, "app::db"));
+		assert!(matches(&route, log::Level::Warn, "app::db::pool"));
+		assert!(matches(&route, log::Level::Warn, "sqlx"));
+		assert!(!matches(&route, log::Level::Info, "app::db"));
+		assert!(!matches(&route, log::Level::Error, "app::dbx"));
+		assert!(!matches(&route, log::Level::Error, "app"));
+
+		assert!(matches(&LoggerRoute::default(), log::Level::Trace, "anything"));
+	}
+
+	#[test]
+	fn logs_are_dispatched_to_routes_then_fallback()
+	{
+		let (all, errors, db, other) = (Capture::default(), Capture::default(), Capture::default(), Capture::default());
+
+		let multi = LoggerMulti::builder()
+			.with_sink(all.builder(log::LevelFilter::Trace))
+			.with_route(LoggerRoute::level(log::Level::Error), errors.builder(log::LevelFilter::Trace))
+			.with_route(LoggerRoute::target("app::db"), db.builder(log::LevelFilter::Trace))
+			.with_fallback(other.builder(log::LevelFilter::Trace))
+			.build();
+
+		log(&multi, log::Level::Info, "app::http", "1");
+		log(&multi, log::Level::Error, "app::http", "2");
+		log(&multi, log::Level::Debug, "app::db::pool", "3");
+		log(&multi, log::Level::Error, "app::db", "4");
+
+		assert_eq!(all.take(), ["1", "2", "3", "4"]);
+		assert_eq!(errors.take(), ["2", "4"]);
+		assert_eq!(db.take(), ["3", "4"]);
+		assert_eq!(other.take(), ["1"]);
+	}
+
+	#[test]
+	fn each_logger_keeps_its_own_level()
+	{
+		let (verbose, quiet) = (Capture::default(), Capture::default());
+
+		let multi = LoggerMulti::builder()
+			.with_sink(verbose.builder(log::LevelFilter::Debug))
+			.with_route(LoggerRoute::target("app"), quiet.builder(log::LevelFilter::Warn))
+			.build();
+
+		assert_eq!(multi.level(), log::LevelFilter::Debug);
+		assert!(!multi.enabled(&log::Metadata::builder().level(log::Level::Trace).target("app").build()));
+
+		log(&multi, log::Level::Info, "app", "1");
+		log(&multi, log::Level::Warn, "app", "2");
+		log(&multi, log::Level::Trace, "app", "3");
+
+		assert_eq!(verbose.take(), ["1", "2"]);
+		assert_eq!(quiet.take(), ["2"]);
+	}
+}
@@ -0,0 +1,82 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Règle de routage d'un log vers un logger d'un [LoggerMulti](crate::LoggerMulti).
+///
+/// Un log correspond à la route s'il remplit toutes ses conditions. Une route
+/// sans condition correspond à tous les logs.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct LoggerRoute
+{
+	level: Option<log::Level>,
+	targets: Vec<String>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerRoute
+{
+	/// Route des logs de ce niveau ou d'un niveau plus grave.
+	pub fn level(level: log::Level) -> Self
+	{
+		Self::default().with_level(level)
+	}
+
+	/// Route des logs dont la cible est `prefix` ou l'un de ses sous-modules.
+	pub fn target(prefix: impl ToString) -> Self
+	{
+		Self::default().with_target(prefix)
+	}
+}
+
+impl LoggerRoute
+{
+	/// Restreint la route aux logs de ce niveau ou d'un niveau plus grave.
+	pub fn with_level(mut self, level: log::Level) -> Self
+	{
+		self.level.replace(level);
+		self
+	}
+
+	/// Restreint la route aux logs dont la cible est `prefix` ou l'un de ses
+	/// sous-modules. Plusieurs cibles peuvent être ajoutées : il suffit
+	/// qu'une d'entre elles corresponde.
+	pub fn with_target(mut self, prefix: impl ToString) -> Self
+	{
+		self.targets.push(prefix.to_string());
+		self
+	}
+
+	/// Vérifie que les métadonnées d'un log correspondent à la route.
+	pub(crate) fn matches(&self, metadata: &log::Metadata) -> bool
+	{
+		if let Some(level) = self.level {
+			if metadata.level() > level {
+				return false;
+			}
+		}
+
+		self.targets.is_empty()
+			|| self.targets.iter().any(|prefix| {
+				let target = metadata.target();
+				target
+					.strip_prefix(prefix.as_str())
+					.is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+			})
+	}
+}
@@ -0,0 +1,211 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use crate::entry::LogEntry;
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Traitement appliqué à un log avant qu'il ne soit formaté : ajout d'un
+/// champ, masquage d'une donnée sensible, renommage d'une cible, ...
+///
+/// Les closures `Fn(LogEntry) -> Option<LogEntry>` implémentent cette
+/// interface.
+pub trait LoggerProcessor
+	: Send
+	+ Sync
+{
+	/// Retourne le log, éventuellement modifié, ou [None] pour l'écarter.
+	fn process(&self, entry: LogEntry) -> Option<LogEntry>;
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Chaîne des processeurs d'un logger, appliqués dans leur ordre d'ajout.
+#[derive(Default)]
+pub(crate) struct LoggerProcessors
+{
+	processors: Vec<Box<dyn LoggerProcessor>>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerProcessors
+{
+	/// Ajoute un processeur à la fin de la chaîne.
+	pub(crate) fn push(&mut self, processor: impl LoggerProcessor + 'static)
+	{
+		self.processors.push(Box::new(processor));
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	pub(crate) fn is_empty(&self) -> bool
+	{
+		self.processors.is_empty()
+	}
+
+	/// Applique la chaîne au log. Le premier processeur qui écarte le log
+	/// interrompt la chaîne.
+	pub(crate) fn process(&self, entry: LogEntry) -> Option<LogEntry>
+	{
+		self.processors
+			.iter()
+			.try_fold(entry, |entry, processor| processor.process(entry))
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<F> LoggerProcessor for F
+where
+	F: Send + Sync,
+	F: Fn(LogEntry) -> Option<LogEntry>,
+{
+	fn process(&self, entry: LogEntry) -> Option<LogEntry>
+	{
+		self(entry)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+	use std::sync::{Arc, Mutex};
+
+	use log::Log;
+
+	use super::LoggerProcessors;
+	use crate::entry::LogEntry;
+	use crate::{Echo, LoggerBackend, LoggerBuilder, LoggerSink};
+
+	/// Destination qui conserve le texte des logs reçus.
+	#[derive(Clone)]
+	#[derive(Default)]
+	struct Capture(Arc<Mutex<Vec<String>>>);
+
+	impl LoggerSink for Capture
+	{
+		fn write(&self, _: &LogEntry, text: &str) -> io::Result<()>
+		{
+			self.0.lock().unwrap().push(text.to_owned());
+			Ok(())
+		}
+	}
+
+	fn entry(message: &str) -> LogEntry
+	{
+		LogEntry::new(log::Level::Info, "app::db", message)
+	}
+
+	#[test]
+	fn enrich()
+	{
+		let mut processors = LoggerProcessors::default();
+		processors.push(|mut entry: LogEntry| {
+			entry.key_values.push((String::from("service"), String::from("api")));
+			Some(entry)
+		});
+
+		let entry = processors.process(entry("hello")).unwrap();
+		assert_eq!(entry.key_values, [(String::from("service"), String::from("api"))]);
+	}
+
+	#[test]
+	fn rewrite()
+	{
+		let mut processors = LoggerProcessors::default();
+		processors.push(|mut entry: LogEntry| {
+			entry.message = entry.message.replace("secret", "******");
+			entry.target = entry.target.replace("app::", "");
+			Some(entry)
+		});
+
+		let entry = processors.process(entry("password=secret")).unwrap();
+		assert_eq!(entry.message, "password=******");
+		assert_eq!(entry.target, "db");
+	}
+
+	#[test]
+	fn drop_interrupts_the_chain()
+	{
+		let calls = Arc::new(AtomicUsize::new(0));
+
+		let mut processors = LoggerProcessors::default();
+		processors.push(|entry: LogEntry| (!entry.message.contains("health")).then_some(entry));
+		processors.push({
+			let calls = calls.clone();
+			move |entry: LogEntry| {
+				calls.fetch_add(1, Ordering::Relaxed);
+				Some(entry)
+			}
+		});
+
+		assert!(processors.process(entry("GET /health")).is_none());
+		assert_eq!(calls.load(Ordering::Relaxed), 0);
+
+		assert!(processors.process(entry("GET /users")).is_some());
+		assert_eq!(calls.load(Ordering::Relaxed), 1);
+	}
+
+	#[test]
+	fn processors_run_in_order()
+	{
+		let mut processors = LoggerProcessors::default();
+		for name in ["a", "b", "c"] {
+			processors.push(move |mut entry: LogEntry| {
+				entry.message.push_str(name);
+				Some(entry)
+			});
+		}
+
+		assert_eq!(processors.process(entry("")).unwrap().message, "abc");
+		assert_eq!(LoggerProcessors::default().process(entry("x")).unwrap().message, "x");
+	}
+
+	#[test]
+	fn processors_run_before_the_format()
+	{
+		let capture = Capture::default();
+
+		let builder = LoggerBackend::builder(capture.clone())
+			.with_processor(|entry: LogEntry| (entry.target != "noisy").then_some(entry))
+			.with_processor(|mut entry: LogEntry| {
+				entry.target = String::from("renamed");
+				Some(entry)
+			})
+			.with_format(|entry: &LogEntry, _: &mut Echo| format!("{} {}\n", entry.target, entry.message));
+		#[cfg(not(feature = "tracing"))]
+		let builder = builder.with_level(log::LevelFilter::Trace);
+		#[cfg(feature = "tracing")]
+		let builder = builder.with_level(tracing::level_filters::LevelFilter::TRACE);
+		let logger = builder.build();
+
+		for target in ["noisy", "app"] {
+			logger.log(
+				&log::Record::builder()
+					.args(format_args!("hello"))
+					.level(log::Level::Info)
+					.target(target)
+					.build(),
+			);
+		}
+
+		assert_eq!(*capture.0.lock().unwrap(), ["renamed hello\n"]);
+	}
+}
@@ -21,6 +21,18 @@ pub struct Settings
 	pub max_level: SettingsLevel,
 	pub target_filters: Vec<String>,
 	pub timestamp: bool,
+	#[serde(default)]
+	pub rotation: SettingsRotation,
+	#[serde(default)]
+	pub timezone: SettingsTimezone,
+	/// Le format des logs. Par défaut, un template.
+	#[serde(default)]
+	pub format: SettingsFormat,
+	/// Template du format [SettingsFormat::TEMPLATE] (voir
+	/// [crate::LoggerTemplateFormat]). Par défaut,
+	/// [crate::LoggerTemplateFormat::DEFAULT].
+	#[serde(default)]
+	pub template: Option<String>,
 }
 
 // ----------- //
@@ -39,6 +51,76 @@ pub enum SettingsLevel
 	WARNING,
 }
 
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub enum SettingsRotation
+{
+	#[default]
+	NEVER,
+	SIZE
+	{
+		max_bytes: u64,
+		backups: usize,
+	},
+	DAILY,
+	HOURLY,
+	MINUTELY,
+}
+
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub enum SettingsFormat
+{
+	#[default]
+	TEMPLATE,
+	JSON,
+	LOGFMT,
+	GELF,
+	OTLP,
+	ECS,
+}
+
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(serde::Deserialize, serde::Serialize)]
+pub enum SettingsTimezone
+{
+	#[default]
+	LOCAL,
+	UTC,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Settings
+{
+	/// Le format des logs décrit par les paramètres, à donner à
+	/// [crate::LoggerBuilder::with_format()].
+	pub fn formatter(&self) -> Result<Box<dyn crate::LoggerFormatter>, crate::LoggerTemplateError>
+	{
+		Ok(match self.format {
+			| SettingsFormat::TEMPLATE => {
+				match self.template.as_deref() {
+					| Some(template) => Box::new(crate::LoggerTemplateFormat::new(template)?),
+					| None => Box::new(crate::LoggerTemplateFormat::default()),
+				}
+			}
+			| SettingsFormat::JSON => Box::new(crate::LoggerJsonFormat),
+			| SettingsFormat::LOGFMT => Box::new(crate::LoggerLogfmtFormat),
+			| SettingsFormat::GELF => Box::new(crate::LoggerGelfFormat::default()),
+			| SettingsFormat::OTLP => Box::new(crate::LoggerOtlpFormat::default()),
+			| SettingsFormat::ECS => Box::new(crate::LoggerEcsFormat::default()),
+		})
+	}
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -52,6 +134,10 @@ impl Default for Settings
 			timestamp: true,
 			max_level: SettingsLevel::TRACE,
 			target_filters: Default::default(),
+			rotation: Default::default(),
+			timezone: Default::default(),
+			format: Default::default(),
+			template: Default::default(),
 		}
 	}
 }
@@ -69,3 +155,28 @@ impl From<SettingsLevel> for log::LevelFilter
 		}
 	}
 }
+
+impl From<SettingsRotation> for crate::LoggerFileRotation
+{
+	fn from(rotation: SettingsRotation) -> Self
+	{
+		match rotation {
+			| SettingsRotation::NEVER => Self::Never,
+			| SettingsRotation::SIZE { max_bytes, backups } => Self::Size { max_bytes, backups },
+			| SettingsRotation::DAILY => Self::Daily,
+			| SettingsRotation::HOURLY => Self::Hourly,
+			| SettingsRotation::MINUTELY => Self::Minutely,
+		}
+	}
+}
+
+impl From<SettingsTimezone> for crate::LoggerFileTimezone
+{
+	fn from(timezone: SettingsTimezone) -> Self
+	{
+		match timezone {
+			| SettingsTimezone::LOCAL => Self::Local,
+			| SettingsTimezone::UTC => Self::Utc,
+		}
+	}
+}
@@ -0,0 +1,168 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io;
+#[cfg(feature = "tracing")]
+use std::sync::{Arc, OnceLock};
+
+use crate::entry::LogEntry;
+#[cfg(feature = "tracing")]
+use crate::processor::LoggerProcessors;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// La destination du subscriber global de `tracing`.
+#[cfg(feature = "tracing")]
+pub(crate) static TRACING_SINK: OnceLock<Arc<dyn LoggerSink>> = OnceLock::new();
+
+// --------- //
+// Interface //
+// --------- //
+
+/// Destination des logs, une fois filtrés et formatés.
+///
+/// Implémenter cette interface permet d'ajouter sa propre destination
+/// (voir [Logger::sink()](crate::Logger::sink)), tout en profitant du
+/// filtrage, du formatage et du mode asynchrone des autres loggers.
+pub trait LoggerSink
+	: Send
+	+ Sync
+{
+	/// Écrit le texte d'un log, formaté à partir de `entry`. Le texte se
+	/// termine par un retour à la ligne.
+	fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>;
+
+	/// Vide les éventuels tampons de la destination.
+	fn flush(&self) -> io::Result<()>
+	{
+		Ok(())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<S> LoggerSink for Box<S>
+where
+	S: ?Sized + LoggerSink,
+{
+	fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+	{
+		(**self).write(entry, text)
+	}
+
+	fn flush(&self) -> io::Result<()>
+	{
+		(**self).flush()
+	}
+}
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Adaptateur permettant à `tracing_subscriber` d'écrire dans une
+/// destination de logs.
+///
+/// Le texte étant formaté par `tracing_subscriber`, les processeurs peuvent
+/// écarter un log ou modifier le [LogEntry] donné à la destination, mais pas
+/// le texte écrit.
+#[cfg(feature = "tracing")]
+pub(crate) struct TracingSink
+{
+	pub(crate) sink: Arc<dyn LoggerSink>,
+	pub(crate) processors: LoggerProcessors,
+}
+
+/// Les données d'un log `tracing` sont déjà formatées par
+/// `tracing_subscriber` : le message du log est le texte reçu.
+#[cfg(feature = "tracing")]
+pub(crate) struct TracingSinkWriter<'a>
+{
+	sink: &'a dyn LoggerSink,
+	processors: &'a LoggerProcessors,
+	entry: LogEntry,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+#[cfg(feature = "tracing")]
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for TracingSink
+{
+	type Writer = TracingSinkWriter<'a>;
+
+	fn make_writer(&'a self) -> Self::Writer
+	{
+		TracingSinkWriter {
+			sink: self.sink.as_ref(),
+			processors: &self.processors,
+			entry: LogEntry::new(log::Level::Info, "", ""),
+		}
+	}
+
+	fn make_writer_for(&'a self, meta: &tracing::Metadata<'_>) -> Self::Writer
+	{
+		let level = match *meta.level() {
+			| tracing::Level::ERROR => log::Level::Error,
+			| tracing::Level::WARN => log::Level::Warn,
+			| tracing::Level::INFO => log::Level::Info,
+			| tracing::Level::DEBUG => log::Level::Debug,
+			| tracing::Level::TRACE => log::Level::Trace,
+		};
+
+		let mut entry = LogEntry::new(level, meta.target(), "");
+		entry.module_path = meta.module_path().map(ToOwned::to_owned);
+		entry.file = meta.file().map(ToOwned::to_owned);
+		entry.line = meta.line();
+
+		TracingSinkWriter {
+			sink: self.sink.as_ref(),
+			processors: &self.processors,
+			entry,
+		}
+	}
+}
+
+#[cfg(feature = "tracing")]
+impl io::Write for TracingSinkWriter<'_>
+{
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+	{
+		let text = String::from_utf8_lossy(buf);
+		self.entry.message = text.trim_end().to_owned();
+
+		if let Some(entry) = self.processors.process(self.entry.clone()) {
+			self.sink.write(&entry, &text)?;
+		}
+
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()>
+	{
+		self.sink.flush()
+	}
+}
+
+/// Vide le logger global.
+pub(crate) fn flush()
+{
+	log::logger().flush();
+
+	#[cfg(feature = "tracing")]
+	if let Some(sink) = TRACING_SINK.get() {
+		_ = sink.flush();
+	}
+}
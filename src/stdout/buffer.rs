@@ -0,0 +1,235 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::{self, BufWriter, Write};
+use std::sync::{Arc, Mutex, Once, Weak};
+
+// ---- //
+// Type //
+// ---- //
+
+pub(crate) type StdoutBufferWriter = Box<dyn Write + Send>;
+
+// --------- //
+// Constante //
+// --------- //
+
+/// Les tampons à vider à la fin du processus.
+static BUFFERS: Mutex<Vec<Weak<Mutex<StdoutBuffer>>>> = Mutex::new(Vec::new());
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Tampons des sorties standards.
+pub(crate) struct StdoutBuffer
+{
+	stdout: BufWriter<StdoutBufferWriter>,
+	stderr: BufWriter<StdoutBufferWriter>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl StdoutBuffer
+{
+	/// Tampons des sorties standards, vidés à la fin du processus.
+	pub(crate) fn shared(capacity: usize) -> Arc<Mutex<Self>>
+	{
+		let buffer = Arc::new(Mutex::new(Self::with_writers(
+			capacity,
+			Box::new(io::stdout()),
+			Box::new(io::stderr()),
+		)));
+		flush_at_exit(&buffer);
+		buffer
+	}
+
+	pub(crate) fn with_writers(capacity: usize, stdout: StdoutBufferWriter, stderr: StdoutBufferWriter) -> Self
+	{
+		Self {
+			stdout: BufWriter::with_capacity(capacity, stdout),
+			stderr: BufWriter::with_capacity(capacity, stderr),
+		}
+	}
+
+	pub(crate) fn write(&mut self, stderr: bool, text: &str) -> io::Result<()>
+	{
+		if stderr {
+			self.stderr.write_all(text.as_bytes())
+		} else {
+			self.stdout.write_all(text.as_bytes())
+		}
+	}
+
+	pub(crate) fn flush(&mut self) -> io::Result<()>
+	{
+		self.stdout.flush()?;
+		self.stderr.flush()
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Vide le tampon à la fin du processus, y compris lors d'un appel à
+/// [std::process::exit].
+fn flush_at_exit(buffer: &Arc<Mutex<StdoutBuffer>>)
+{
+	static REGISTER: Once = Once::new();
+
+	if let Ok(mut buffers) = BUFFERS.lock() {
+		buffers.retain(|buffer| buffer.strong_count() > 0);
+		buffers.push(Arc::downgrade(buffer));
+	}
+
+	REGISTER.call_once(|| {
+		// SAFETY: `flush_buffers` est une fonction `extern "C"` sans argument,
+		// comme l'attend `atexit`, et ne panique pas.
+		unsafe {
+			libc::atexit(flush_buffers);
+		}
+	});
+}
+
+/// Vide les tampons encore vivants.
+///
+/// NOTE: un tampon verrouillé (par exemple par un autre thread au moment de
+/// la sortie) est ignoré plutôt qu'attendu, afin de ne jamais bloquer la fin
+/// du processus.
+extern "C" fn flush_buffers()
+{
+	let Ok(buffers) = BUFFERS.try_lock() else {
+		return;
+	};
+
+	for buffer in buffers.iter().filter_map(Weak::upgrade) {
+		if let Ok(mut buffer) = buffer.try_lock() {
+			_ = buffer.flush();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io::{self, Write};
+	use std::sync::{Arc, Mutex};
+
+	use super::{flush_at_exit, flush_buffers, StdoutBuffer};
+	use crate::stdout::sink::StdoutSink;
+	use crate::{LogEntry, LoggerSink, LoggerStdoutRouting};
+
+	/// Sortie de test, partagée avec le test.
+	#[derive(Clone)]
+	#[derive(Default)]
+	struct Output(Arc<Mutex<Vec<u8>>>);
+
+	impl Output
+	{
+		fn text(&self) -> String
+		{
+			String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+		}
+	}
+
+	impl Write for Output
+	{
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+		{
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()>
+		{
+			Ok(())
+		}
+	}
+
+	fn buffer(capacity: usize) -> (Arc<Mutex<StdoutBuffer>>, Output, Output)
+	{
+		let (stdout, stderr) = (Output::default(), Output::default());
+		let buffer = StdoutBuffer::with_writers(capacity, Box::new(stdout.clone()), Box::new(stderr.clone()));
+		(Arc::new(Mutex::new(buffer)), stdout, stderr)
+	}
+
+	fn sink(buffer: &Arc<Mutex<StdoutBuffer>>) -> StdoutSink
+	{
+		StdoutSink {
+			routing: LoggerStdoutRouting::default(),
+			buffer: Some(buffer.clone()),
+		}
+	}
+
+	fn write(sink: &StdoutSink, level: log::Level, text: &str)
+	{
+		sink.write(&LogEntry::new(level, "test", text.trim_end()), text).unwrap();
+	}
+
+	#[test]
+	fn full_buffer_is_flushed()
+	{
+		let (buffer, stdout, _) = buffer(16);
+		let sink = sink(&buffer);
+
+		write(&sink, log::Level::Info, "0123456789\n");
+		assert_eq!(stdout.text(), "");
+
+		write(&sink, log::Level::Info, "abcdefghij\n");
+		assert_eq!(stdout.text(), "0123456789\n");
+	}
+
+	#[test]
+	fn error_log_flushes_the_buffer()
+	{
+		let (buffer, stdout, stderr) = buffer(1024);
+		let sink = sink(&buffer);
+
+		write(&sink, log::Level::Info, "info\n");
+		write(&sink, log::Level::Warn, "warn\n");
+		assert_eq!(stdout.text(), "");
+
+		write(&sink, log::Level::Error, "error\n");
+		assert_eq!(stdout.text(), "info\nwarn\n");
+		assert_eq!(stderr.text(), "error\n");
+	}
+
+	#[test]
+	fn explicit_flush()
+	{
+		let (buffer, stdout, _) = buffer(1024);
+		let sink = sink(&buffer);
+
+		write(&sink, log::Level::Info, "info\n");
+		assert_eq!(stdout.text(), "");
+
+		sink.flush().unwrap();
+		assert_eq!(stdout.text(), "info\n");
+	}
+
+	#[test]
+	fn exit_flush_skips_locked_buffers()
+	{
+		let (buffer, stdout, _) = buffer(1024);
+		flush_at_exit(&buffer);
+
+		buffer.lock().unwrap().write(false, "info\n").unwrap();
+
+		let guard = buffer.lock().unwrap();
+		flush_buffers();
+		drop(guard);
+		assert_eq!(stdout.text(), "");
+
+		flush_buffers();
+		assert_eq!(stdout.text(), "info\n");
+	}
+}
@@ -8,89 +8,90 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+use super::buffer::StdoutBuffer;
 use super::extension::LoggerStdoutBuilderExtension;
-use super::LoggerFilter;
-use crate::builder::LoggerFormatFn;
-use crate::{LoggerBuilder, LoggerStdout};
+use super::sink::StdoutSink;
+use super::LoggerStdoutRouting;
+use crate::sink::LoggerSink;
+use crate::{LoggerBackend, LoggerBackendBuilder, LoggerBackendDestination, LoggerStdout};
+
+// ---- //
+// Type //
+// ---- //
+
+pub type LoggerStdoutBuilder = LoggerBackendBuilder<LoggerStdoutDestination>;
 
 // --------- //
 // Structure //
 // --------- //
 
+/// Destination des logs d'un [LoggerStdoutBuilder]: la sortie standard.
 #[derive(Default)]
-pub struct LoggerStdoutBuilder
+pub struct LoggerStdoutDestination
 {
-	colorized: bool,
-	timestamp: bool,
-	#[cfg(not(feature = "tracing"))]
-	level: Option<log::LevelFilter>,
-	#[cfg(feature = "tracing")]
-	level: Option<tracing::level_filters::LevelFilter>,
-	format_fn: Option<LoggerFormatFn>,
-	filter: LoggerFilter,
+	routing: LoggerStdoutRouting,
+	buffer_capacity: Option<usize>,
 }
 
 // -------------- //
-// Implémentation // -> Interface
+// Implémentation //
 // -------------- //
 
-impl LoggerBuilder<LoggerStdout> for LoggerStdoutBuilder
+impl LoggerStdoutBuilder
 {
-	fn filter<F>(mut self, predicate: F, dependency: impl ToString) -> Self
-	where
-		F: 'static,
-		F: Send + Sync,
-		F: Fn(&log::Metadata) -> bool,
+	/// Choix de la sortie standard (stdout ou stderr) des logs selon leur
+	/// niveau. Par défaut, seuls les logs d'erreurs sont envoyés sur stderr.
+	///
+	/// Un niveau (par exemple `log::Level::Warn`) équivaut à
+	/// [LoggerStdoutRouting::Threshold].
+	pub fn with_routing(mut self, routing: impl Into<LoggerStdoutRouting>) -> Self
 	{
-		self.filter.push_callback(predicate);
-		self.filter.add_dependency(dependency);
+		self.destination.routing = routing.into();
 		self
 	}
 
-	fn with_color(mut self, colorized: impl Into<bool>) -> Self
+	/// Les logs sont mis en tampon (de `capacity` octets) au lieu d'être
+	/// écrits un par un sur la sortie standard.
+	///
+	/// Le tampon est vidé par [log::Log::flush] (`log::logger().flush()`),
+	/// après chaque log d'erreur, lorsque la garde retournée par
+	/// `try_initialize()` est détruite (voir [crate::LoggerGuard]) et à la
+	/// fin du processus, y compris lors d'un appel à [std::process::exit].
+	///
+	/// NOTE: si un autre thread est en train d'écrire dans le tampon au
+	/// moment de la sortie du processus, ce tampon n'est pas vidé.
+	pub fn with_buffer(mut self, capacity: usize) -> Self
 	{
-		self.colorized = colorized.into();
+		self.destination.buffer_capacity.replace(capacity);
 		self
 	}
+}
 
-	fn with_format(mut self, format: LoggerFormatFn) -> Self
-	{
-		self.format_fn.replace(format);
-		self
-	}
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
 
-	#[cfg(not(feature = "tracing"))]
-	fn with_level(mut self, level: impl Into<log::LevelFilter>) -> Self
+impl Default for LoggerStdoutBuilder
+{
+	fn default() -> Self
 	{
-		self.level.replace(level.into());
-		self
+		Self::with_destination(LoggerStdoutDestination::default())
 	}
+}
 
-	#[cfg(feature = "tracing")]
-	fn with_level(mut self, level: impl Into<tracing::level_filters::LevelFilter>) -> Self
-	{
-		self.level.replace(level.into());
-		self
-	}
+impl LoggerBackendDestination for LoggerStdoutDestination
+{
+	type Logger = LoggerStdout;
 
-	fn with_timestamp(mut self, b: impl Into<bool>) -> Self
+	fn build(self, backend: impl FnOnce(Box<dyn LoggerSink>) -> LoggerBackend) -> Self::Logger
 	{
-		self.timestamp = b.into();
-		self
-	}
+		let sink = StdoutSink {
+			routing: self.routing,
+			buffer: self.buffer_capacity.map(StdoutBuffer::shared),
+		};
 
-	fn build(self) -> LoggerStdout
-	{
 		LoggerStdout {
-			cache: Default::default(),
-			colorized: self.colorized,
-			filter: self.filter,
-			format_fn: self.format_fn.unwrap_or(LoggerStdout::default_format),
-			#[cfg(not(feature = "tracing"))]
-			level: self.level.unwrap_or(log::LevelFilter::Off),
-			#[cfg(feature = "tracing")]
-			level: self.level.unwrap_or(tracing_subscriber::filter::LevelFilter::OFF),
-			timestamp: self.timestamp,
+			backend: backend(Box::new(sink)),
 		}
 	}
 }
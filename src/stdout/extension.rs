@@ -9,7 +9,7 @@
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
 use crate::initiator::LoggerInitiator;
-use crate::{LoggerBuilder, LoggerStdout};
+use crate::{LoggerBuilder, LoggerGuard, LoggerStdout};
 
 // --------- //
 // Interface //
@@ -23,4 +23,18 @@ pub trait LoggerStdoutBuilderExtension
 	{
 		LoggerInitiator::stdout(self).expect("L'initialisation du logger (stdout)");
 	}
+
+	/// Initialise le logger et retourne une garde qui écrit les logs en
+	/// attente lorsqu'elle est détruite.
+	#[cfg(not(feature = "tracing"))]
+	fn try_initialize(self) -> Result<LoggerGuard, log::SetLoggerError>
+	{
+		LoggerInitiator::stdout(self).map(|()| LoggerGuard::new())
+	}
+
+	#[cfg(feature = "tracing")]
+	fn try_initialize(self) -> Result<LoggerGuard, &'static str>
+	{
+		LoggerInitiator::stdout(self).map(|()| LoggerGuard::new())
+	}
 }
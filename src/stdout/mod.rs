@@ -8,19 +8,16 @@
 // ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
 // ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
 
+mod buffer;
 mod builder;
 mod extension;
+mod sink;
 
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::ops;
 
-use console::style;
-
-pub use self::builder::LoggerStdoutBuilder;
+pub use self::builder::{LoggerStdoutBuilder, LoggerStdoutDestination};
 pub use self::extension::LoggerStdoutBuilderExtension;
-use crate::builder::{LoggerFilterCallback, LoggerFormatFn};
-use crate::echo::Echo;
-use crate::layout;
+use crate::LoggerBackend;
 
 // --------- //
 // Structure //
@@ -29,22 +26,26 @@ use crate::layout;
 // Contrat
 pub struct LoggerStdout
 {
-	pub(crate) colorized: bool,
-	pub(crate) timestamp: bool,
-	#[cfg(not(feature = "tracing"))]
-	pub(crate) level: log::LevelFilter,
-	#[cfg(feature = "tracing")]
-	pub(crate) level: tracing::level_filters::LevelFilter,
-	pub(crate) format_fn: LoggerFormatFn,
-	pub(crate) filter: LoggerFilter,
-	pub(crate) cache: Arc<Mutex<HashMap<String, bool>>>,
+	pub(crate) backend: LoggerBackend,
 }
 
-#[derive(Default)]
-pub struct LoggerFilter
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Choix de la sortie standard (stdout ou stderr) d'un log selon son niveau.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(Eq, PartialEq)]
+pub enum LoggerStdoutRouting
 {
-	callbacks: Vec<Box<LoggerFilterCallback>>,
-	dependencies: Vec<String>,
+	/// Tous les logs sont envoyés sur stdout.
+	Stdout,
+	/// Tous les logs sont envoyés sur stderr (convention des CLI).
+	Stderr,
+	/// Les logs de ce niveau, ou d'un niveau plus grave, sont envoyés sur
+	/// stderr, les autres sur stdout.
+	Threshold(log::Level),
 }
 
 // -------------- //
@@ -53,110 +54,63 @@ pub struct LoggerFilter
 
 impl LoggerStdout
 {
-	pub fn builder() -> builder::LoggerStdoutBuilder
+	pub fn builder() -> LoggerStdoutBuilder
 	{
-		builder::LoggerStdoutBuilder::default()
+		LoggerStdoutBuilder::default()
 	}
 }
 
-impl LoggerStdout
+impl LoggerStdoutRouting
 {
-	#[cfg(not(feature = "tracing"))]
-	pub fn level(&self) -> log::LevelFilter
+	/// Est-ce que les logs de ce niveau sont envoyés sur stderr?
+	pub fn is_stderr(&self, level: log::Level) -> bool
 	{
-		self.level
+		match *self {
+			| Self::Stdout => false,
+			| Self::Stderr => true,
+			| Self::Threshold(threshold) => level <= threshold,
+		}
 	}
+}
 
-	#[cfg(feature = "tracing")]
-	pub fn level(&self) -> tracing::level_filters::LevelFilter
-	{
-		self.level
-	}
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
 
-	fn default_format(message: &std::fmt::Arguments, record: &log::Record, echo: &mut Echo) -> String
+/// Seuls les logs d'erreurs sont envoyés sur stderr.
+impl Default for LoggerStdoutRouting
+{
+	fn default() -> Self
 	{
-		let local_date_format = echo
-			.time
-			.map(|local_datetime| local_datetime.format("%Y-%m-%d@%H:%M:%S"));
-
-		if let Some(time) = local_date_format {
-			echo.table.add_line([
-				layout::Cell::new(&echo.level).with_alignment(layout::Alignment::Right),
-				layout::Cell::new(&echo.delimiter),
-				layout::Cell::new(time),
-				layout::Cell::new(&echo.delimiter),
-				layout::Cell::new(format!(
-					"{} {} {}",
-					if echo.colorized {
-						style(record.target()).black().bright()
-					} else {
-						style(record.target())
-					},
-					if echo.colorized { style("->").red() } else { style("->") },
-					message
-				)),
-			]);
-		} else {
-			echo.table.add_line([
-				layout::Cell::new(&echo.level).with_alignment(layout::Alignment::Right),
-				layout::Cell::new(&echo.delimiter),
-				layout::Cell::new(style(record.target()).black().bright()),
-				layout::Cell::new(style("->").red()),
-				layout::Cell::new(message),
-			]);
-		}
-
-		echo.table.render()
+		Self::Threshold(log::Level::Error)
 	}
 }
 
-impl LoggerFilter
+/// Les logs de ce niveau, ou d'un niveau plus grave, sont envoyés sur stderr
+/// (voir [LoggerStdoutRouting::Threshold]).
+impl From<log::Level> for LoggerStdoutRouting
 {
-	/// Pousse une callback dans le tableau des callbacks.
-	pub(crate) fn push_callback<F>(&mut self, predicate: F)
-	where
-		F: 'static,
-		F: Send + Sync,
-		F: Fn(&log::Metadata) -> bool,
+	fn from(threshold: log::Level) -> Self
 	{
-		self.callbacks.push(Box::new(predicate));
+		Self::Threshold(threshold)
 	}
+}
 
-	/// Ajoute une dépendance dans le tableau des dépendances.
-	pub(crate) fn add_dependency(&mut self, dependency: impl ToString)
+impl ops::Deref for LoggerStdout
+{
+	type Target = LoggerBackend;
+
+	fn deref(&self) -> &Self::Target
 	{
-		self.dependencies.push(dependency.to_string());
+		&self.backend
 	}
 }
 
-// -------------- //
-// Implémentation // -> Interface
-// -------------- //
-
 impl log::Log for LoggerStdout
 {
-	/// On ne veut pas afficher les logs si le niveau est à
-	/// [log::LevelFilter::Off].
-	///
-	/// Des conditions utilisateurs peuvent être utilisées pour filtrer les
-	/// logs.
 	fn enabled(&self, metadata: &log::Metadata) -> bool
 	{
-		let mut guard = self.cache.lock().expect("cache guard");
-
-		metadata.level() != log::LevelFilter::Off
-			&& (self.filter.callbacks.is_empty()
-				|| self.filter.callbacks.iter().enumerate().any(|(idx, once_fn)| {
-					let cache_key = format!("{}_{}", self.filter.dependencies[idx], metadata.target());
-
-					if let Some(has) = guard.get(&cache_key) {
-						return *has;
-					}
-
-					let is_ok = once_fn(metadata);
-					guard.insert(cache_key, is_ok);
-					is_ok
-				}))
+		self.backend.enabled(metadata)
 	}
 
 	/// Affiche le log.
@@ -165,47 +119,44 @@ impl log::Log for LoggerStdout
 	// les afficher nous fait perdre un temps considérable.
 	fn log(&self, record: &log::Record)
 	{
-		if !self.enabled(record.metadata()) {
-			return;
-		}
+		self.backend.log(record);
+	}
 
-		let message = record.args();
-		if message.to_string().trim().is_empty() {
-			return;
-		}
+	fn flush(&self)
+	{
+		self.backend.flush();
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::LoggerStdoutRouting;
 
-		let level = if self.colorized {
-			match record.level() {
-				| log::Level::Error => style("ERROR").red(),
-				| log::Level::Warn => style(" WARN").yellow(),
-				| log::Level::Info => style(" INFO").blue(),
-				| log::Level::Debug => style("DEBUG").magenta(),
-				| log::Level::Trace => style("TRACE").white(),
-			}
-			.to_string()
-		} else {
-			record.level().to_string()
-		};
-
-		let mut table = layout::GridLayout::default().define_max_width(120).without_boarder();
-
-		let mut echo = Echo {
-			colorized: self.colorized,
-			delimiter: if self.colorized { style("|").red() } else { style("|") }.to_string(),
-			level,
-			record_level: record.level(),
-			table: &mut table,
-			time: if self.timestamp {
-				Some(chrono::Local::now())
-			} else {
-				None
-			},
-		};
-
-		let text = (self.format_fn)(message, record, &mut echo);
-
-		echo.log(text);
+	/// Les niveaux envoyés sur stderr, du plus grave au moins grave.
+	fn stderr_levels(routing: LoggerStdoutRouting) -> Vec<log::Level>
+	{
+		log::Level::iter().filter(|level| routing.is_stderr(*level)).collect()
 	}
 
-	fn flush(&self) {}
+	#[test]
+	fn routing_by_level()
+	{
+		use log::Level::*;
+
+		assert!(stderr_levels(LoggerStdoutRouting::Stdout).is_empty());
+		assert_eq!(stderr_levels(LoggerStdoutRouting::Stderr), [Error, Warn, Info, Debug, Trace]);
+		assert_eq!(stderr_levels(LoggerStdoutRouting::default()), [Error]);
+		assert_eq!(stderr_levels(LoggerStdoutRouting::Threshold(Error)), [Error]);
+		assert_eq!(stderr_levels(LoggerStdoutRouting::Threshold(Warn)), [Error, Warn]);
+		assert_eq!(stderr_levels(LoggerStdoutRouting::Threshold(Info)), [Error, Warn, Info]);
+		assert_eq!(stderr_levels(LoggerStdoutRouting::Threshold(Debug)), [Error, Warn, Info, Debug]);
+		assert_eq!(stderr_levels(LoggerStdoutRouting::Threshold(Trace)), [Error, Warn, Info, Debug, Trace]);
+	}
+
+	#[test]
+	fn routing_from_level()
+	{
+		assert_eq!(LoggerStdoutRouting::from(log::Level::Warn), LoggerStdoutRouting::Threshold(log::Level::Warn));
+	}
 }
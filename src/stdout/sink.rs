@@ -0,0 +1,68 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use super::buffer::StdoutBuffer;
+use super::LoggerStdoutRouting;
+use crate::entry::LogEntry;
+use crate::sink::LoggerSink;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs sur les sorties standards.
+pub(crate) struct StdoutSink
+{
+	pub(crate) routing: LoggerStdoutRouting,
+	pub(crate) buffer: Option<Arc<Mutex<StdoutBuffer>>>,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerSink for StdoutSink
+{
+	fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let stderr = self.routing.is_stderr(entry.level);
+
+		let Some(buffer) = self.buffer.as_ref() else {
+			return if stderr {
+				io::stderr().write_all(text.as_bytes())
+			} else {
+				io::stdout().write_all(text.as_bytes())
+			};
+		};
+
+		let mut buffer = buffer.lock().expect("stdout buffer guard");
+		buffer.write(stderr, text)?;
+
+		if entry.level == log::Level::Error {
+			buffer.flush()?;
+		}
+
+		Ok(())
+	}
+
+	fn flush(&self) -> io::Result<()>
+	{
+		match self.buffer.as_ref() {
+			| Some(buffer) => buffer.lock().expect("stdout buffer guard").flush(),
+			| None => {
+				io::stdout().flush()?;
+				io::stderr().flush()
+			}
+		}
+	}
+}
@@ -0,0 +1,266 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+use super::{bind_udp, resolve};
+use super::tcp::TcpConnection;
+use crate::entry::LogEntry;
+use crate::sink::LoggerSink;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs GELF vers un serveur Graylog, en UDP ou en TCP.
+///
+/// Le texte des logs doit être au format GELF (voir
+/// [crate::LoggerGelfFormat] et [crate::Logger::gelf()]).
+pub struct LoggerGelfTransport
+{
+	address: SocketAddr,
+	protocol: Protocol,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+enum Protocol
+{
+	/// Un datagramme par log, découpé en morceaux lorsqu'il est trop grand.
+	Udp(UdpSocket),
+	/// Les logs sont séparés par un octet nul. La connexion est rétablie
+	/// au log suivant lorsqu'elle est perdue.
+	Tcp(TcpConnection),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerGelfTransport
+{
+	/// Taille maximale d'un datagramme, en-tête des morceaux inclus.
+	const CHUNK_SIZE: usize = 8192;
+	/// En-tête d'un morceau : magie (2), identifiant (8), numéro (1) et
+	/// nombre de morceaux (1).
+	const CHUNK_HEADER_SIZE: usize = 12;
+	/// Nombre maximal de morceaux d'un message.
+	const MAX_CHUNKS: usize = 128;
+
+	/// Envoie les logs en UDP.
+	pub fn udp(address: impl ToSocketAddrs) -> io::Result<Self>
+	{
+		let address = resolve(address)?;
+
+		Ok(Self {
+			address,
+			protocol: Protocol::Udp(bind_udp(address)?),
+		})
+	}
+
+	/// Envoie les logs en TCP. La connexion est établie dès maintenant.
+	///
+	/// Une connexion perdue est rétablie au log suivant. Après un échec de
+	/// connexion, les logs des 5 secondes suivantes sont écartés sans
+	/// nouvelle tentative.
+	pub fn tcp(address: impl ToSocketAddrs) -> io::Result<Self>
+	{
+		let address = resolve(address)?;
+
+		Ok(Self {
+			address,
+			protocol: Protocol::Tcp(TcpConnection::connect(address)?),
+		})
+	}
+
+	/// L'adresse du serveur.
+	pub fn address(&self) -> SocketAddr
+	{
+		self.address
+	}
+}
+
+impl LoggerGelfTransport
+{
+	fn send_udp(&self, socket: &UdpSocket, message: &[u8]) -> io::Result<()>
+	{
+		if message.len() <= Self::CHUNK_SIZE {
+			socket.send_to(message, self.address)?;
+			return Ok(());
+		}
+
+		let payload_size = Self::CHUNK_SIZE - Self::CHUNK_HEADER_SIZE;
+		let count = message.len().div_ceil(payload_size);
+		if count > Self::MAX_CHUNKS {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				"Le message GELF est trop grand pour être envoyé en UDP",
+			));
+		}
+
+		let id = chunk_id();
+		let mut datagram = Vec::with_capacity(Self::CHUNK_SIZE);
+
+		for (seq, chunk) in message.chunks(payload_size).enumerate() {
+			datagram.clear();
+			datagram.extend_from_slice(&[0x1e, 0x0f]);
+			datagram.extend_from_slice(&id);
+			datagram.extend_from_slice(&[seq as u8, count as u8]);
+			datagram.extend_from_slice(chunk);
+			socket.send_to(&datagram, self.address)?;
+		}
+
+		Ok(())
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerSink for LoggerGelfTransport
+{
+	fn write(&self, _: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let message = text.trim_end().as_bytes();
+
+		match &self.protocol {
+			| Protocol::Udp(socket) => self.send_udp(socket, message),
+			| Protocol::Tcp(connection) => {
+				let mut frame = Vec::with_capacity(message.len() + 1);
+				frame.extend_from_slice(message);
+				frame.push(0);
+				connection.send(&frame)
+			}
+		}
+	}
+
+	fn flush(&self) -> io::Result<()>
+	{
+		match &self.protocol {
+			| Protocol::Udp(_) => Ok(()),
+			| Protocol::Tcp(connection) => connection.flush(),
+		}
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Identifiant des morceaux d'un message, unique pour ce processus.
+fn chunk_id() -> [u8; 8]
+{
+	use std::sync::atomic::{AtomicU64, Ordering};
+
+	static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+	let nanos = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map(|elapsed| elapsed.as_nanos() as u64)
+		.unwrap_or_default();
+
+	(nanos ^ COUNTER.fetch_add(1, Ordering::Relaxed).rotate_left(32)).to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io::Read;
+	use std::net::{TcpListener, UdpSocket};
+	use std::time::Duration;
+
+	use super::LoggerGelfTransport;
+	use crate::{LogEntry, LoggerSink};
+
+	fn entry() -> LogEntry
+	{
+		LogEntry::new(log::Level::Info, "test", "message")
+	}
+
+	#[test]
+	fn udp_small_message_is_one_datagram()
+	{
+		let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+		server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+		let transport = LoggerGelfTransport::udp(server.local_addr().unwrap()).unwrap();
+
+		transport.write(&entry(), "{\"short_message\":\"hi\"}\n").unwrap();
+
+		let mut buffer = [0; 65536];
+		let size = server.recv(&mut buffer).unwrap();
+		assert_eq!(&buffer[..size], b"{\"short_message\":\"hi\"}");
+	}
+
+	#[test]
+	fn udp_large_message_is_chunked()
+	{
+		let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+		server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+		let transport = LoggerGelfTransport::udp(server.local_addr().unwrap()).unwrap();
+
+		let message: String = (0..20_000).map(|n| char::from(b'a' + (n % 26) as u8)).collect();
+		transport.write(&entry(), &message).unwrap();
+
+		let payload_size = LoggerGelfTransport::CHUNK_SIZE - LoggerGelfTransport::CHUNK_HEADER_SIZE;
+		let count = message.len().div_ceil(payload_size);
+		assert_eq!(count, 3);
+
+		let mut buffer = [0; 65536];
+		let mut id = None;
+		let mut payload = Vec::new();
+
+		for seq in 0..count {
+			let size = server.recv(&mut buffer).unwrap();
+			let datagram = &buffer[..size];
+
+			assert!(size <= LoggerGelfTransport::CHUNK_SIZE);
+			assert_eq!(&datagram[..2], [0x1e, 0x0f]);
+			assert_eq!(*id.get_or_insert(datagram[2..10].to_vec()), &datagram[2..10]);
+			assert_eq!(datagram[10], seq as u8);
+			assert_eq!(datagram[11], count as u8);
+			payload.extend_from_slice(&datagram[12..]);
+		}
+
+		assert_eq!(payload, message.as_bytes());
+	}
+
+	#[test]
+	fn udp_message_over_max_chunks_is_rejected()
+	{
+		let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+		let transport = LoggerGelfTransport::udp(server.local_addr().unwrap()).unwrap();
+
+		let message = "x".repeat(LoggerGelfTransport::CHUNK_SIZE * (LoggerGelfTransport::MAX_CHUNKS + 1));
+		let error = transport.write(&entry(), &message).unwrap_err();
+		assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	fn tcp_frames_are_nul_terminated()
+	{
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let transport = LoggerGelfTransport::tcp(listener.local_addr().unwrap()).unwrap();
+		let (mut stream, _) = listener.accept().unwrap();
+		stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+		transport.write(&entry(), "{\"n\":1}\n").unwrap();
+		transport.write(&entry(), "{\"n\":2}\n").unwrap();
+		transport.flush().unwrap();
+		drop(transport);
+
+		let mut received = Vec::new();
+		stream.read_to_end(&mut received).unwrap();
+		assert_eq!(received, b"{\"n\":1}\0{\"n\":2}\0");
+	}
+}
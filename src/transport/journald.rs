@@ -0,0 +1,259 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io;
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+
+use crate::entry::LogEntry;
+use crate::format::syslog_severity;
+use crate::sink::LoggerSink;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs vers systemd-journald, par son protocole natif.
+///
+/// Chaque log est un datagramme de champs du journal : `MESSAGE` (le texte
+/// du log, sans retour à la ligne final), `PRIORITY` (la sévérité syslog
+/// déduite du niveau), `CODE_FILE`, `CODE_LINE`, `CODE_MODULE`, `TARGET`,
+/// `THREAD`, `SYSLOG_IDENTIFIER`, ainsi que les champs personnalisés et les
+/// paires clé-valeur du log. Ces champs peuvent être filtrés avec
+/// `journalctl`, par exemple `journalctl TARGET=app::db`.
+///
+/// Le nom des champs personnalisés et des clés est mis en majuscules ; les
+/// caractères autres que les lettres ASCII, les chiffres et `_` sont
+/// remplacés par `_`. Un nom identique à celui d'un des champs ci-dessus
+/// est préfixé par `FIELD_` (`FIELD_MESSAGE`, `FIELD_TARGET`, ...), afin de
+/// ne pas doubler ce champ.
+///
+/// NOTE: les logs trop grands pour un datagramme ne sont pas envoyés.
+pub struct LoggerJournaldTransport
+{
+	socket: UnixDatagram,
+	path: PathBuf,
+	syslog_identifier: String,
+	fields: Vec<(String, String)>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerJournaldTransport
+{
+	/// Envoie les logs au journal du système, par la socket
+	/// `/run/systemd/journal/socket`.
+	pub fn local() -> io::Result<Self>
+	{
+		Self::unix("/run/systemd/journal/socket")
+	}
+
+	/// Envoie les logs par une socket Unix de type datagramme.
+	pub fn unix(path: impl AsRef<Path>) -> io::Result<Self>
+	{
+		let syslog_identifier = std::env::current_exe()
+			.ok()
+			.and_then(|path| path.file_stem().map(|name| name.to_string_lossy().into_owned()))
+			.unwrap_or_else(|| String::from(env!("CARGO_PKG_NAME")));
+
+		Ok(Self {
+			socket: UnixDatagram::unbound()?,
+			path: path.as_ref().to_owned(),
+			syslog_identifier,
+			fields: Default::default(),
+		})
+	}
+
+	/// Le champ `SYSLOG_IDENTIFIER`. Par défaut, le nom de l'exécutable.
+	pub fn with_syslog_identifier(mut self, identifier: impl ToString) -> Self
+	{
+		self.syslog_identifier = identifier.to_string();
+		self
+	}
+
+	/// Ajoute un champ envoyé avec chaque log.
+	pub fn with_field(mut self, name: impl AsRef<str>, value: impl ToString) -> Self
+	{
+		self.fields.push((user_field_name(name.as_ref()), value.to_string()));
+		self
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerSink for LoggerJournaldTransport
+{
+	fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let mut datagram = Vec::new();
+
+		push_field(&mut datagram, "MESSAGE", text.trim_end_matches(['\r', '\n']));
+		push_field(&mut datagram, "PRIORITY", &syslog_severity(entry.level).to_string());
+		push_field(&mut datagram, "SYSLOG_IDENTIFIER", &self.syslog_identifier);
+		push_field(&mut datagram, "TARGET", &entry.target);
+		push_field(&mut datagram, "THREAD", &entry.thread);
+		if let Some(file) = entry.file.as_deref() {
+			push_field(&mut datagram, "CODE_FILE", file);
+		}
+		if let Some(line) = entry.line {
+			push_field(&mut datagram, "CODE_LINE", &line.to_string());
+		}
+		if let Some(module) = entry.module_path.as_deref() {
+			push_field(&mut datagram, "CODE_MODULE", module);
+		}
+
+		for (name, value) in self.fields.iter() {
+			push_field(&mut datagram, name, value);
+		}
+		for (key, value) in entry.key_values.iter() {
+			push_field(&mut datagram, &user_field_name(key), value);
+		}
+
+		self.socket.send_to(&datagram, &self.path).map(|_| ())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Ajoute un champ au datagramme. Une valeur sur plusieurs lignes est
+/// précédée de sa taille, sur 64 bits en petit-boutiste.
+fn push_field(datagram: &mut Vec<u8>, name: &str, value: &str)
+{
+	datagram.extend_from_slice(name.as_bytes());
+
+	if value.contains('\n') {
+		datagram.push(b'\n');
+		datagram.extend_from_slice(&(value.len() as u64).to_le_bytes());
+	} else {
+		datagram.push(b'=');
+	}
+
+	datagram.extend_from_slice(value.as_bytes());
+	datagram.push(b'\n');
+}
+
+/// Le nom d'un champ du journal : au plus 64 lettres ASCII majuscules,
+/// chiffres ou `_`, ne commençant ni par un chiffre ni par `_`, ces derniers
+/// étant réservés au journal.
+fn field_name(name: &str) -> String
+{
+	let name: String = name
+		.chars()
+		.map(|ch| {
+			if ch.is_ascii_alphanumeric() {
+				ch.to_ascii_uppercase()
+			} else {
+				'_'
+			}
+		})
+		.skip_while(|ch| *ch == '_' || ch.is_ascii_digit())
+		.take(64)
+		.collect();
+
+	if name.is_empty() {
+		String::from("FIELD")
+	} else {
+		name
+	}
+}
+
+/// Le nom d'un champ personnalisé ou d'une clé : les noms des champs envoyés
+/// par ce transport reçoivent le préfixe `FIELD_`.
+fn user_field_name(name: &str) -> String
+{
+	const RESERVED: [&str; 8] = [
+		"MESSAGE",
+		"PRIORITY",
+		"SYSLOG_IDENTIFIER",
+		"TARGET",
+		"THREAD",
+		"CODE_FILE",
+		"CODE_LINE",
+		"CODE_MODULE",
+	];
+
+	let name = field_name(name);
+	if RESERVED.contains(&name.as_str()) {
+		format!("FIELD_{name}")
+	} else {
+		name
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::os::unix::net::UnixDatagram;
+	use std::time::Duration;
+
+	use super::{field_name, user_field_name, LoggerJournaldTransport};
+	use crate::{LogEntry, LoggerSink};
+
+	#[test]
+	fn field_names_are_sanitized()
+	{
+		assert_eq!(field_name("user id"), "USER_ID");
+		assert_eq!(field_name("_9user"), "USER");
+		assert_eq!(field_name("é"), "FIELD");
+		assert_eq!(field_name(&"a".repeat(80)).len(), 64);
+		assert_eq!(user_field_name("message"), "FIELD_MESSAGE");
+		assert_eq!(user_field_name("code.line"), "FIELD_CODE_LINE");
+		assert_eq!(user_field_name("user"), "USER");
+	}
+
+	#[test]
+	fn datagram_uses_the_native_protocol()
+	{
+		let dir = crate::file::temp_dir("journald");
+		let path = dir.join("socket");
+		let server = UnixDatagram::bind(&path).unwrap();
+		server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+		let transport = LoggerJournaldTransport::unix(&path)
+			.unwrap()
+			.with_syslog_identifier("app")
+			.with_field("priority", "high");
+
+		let mut entry = LogEntry::new(log::Level::Warn, "app::db", "unused");
+		entry.thread = String::from("main");
+		entry.file = Some(String::from("src/db.rs"));
+		entry.line = Some(42);
+		entry.module_path = None;
+		entry.key_values = vec![(String::from("user"), String::from("a\nb"))];
+
+		transport.write(&entry, "hello\n").unwrap();
+
+		let mut buffer = [0; 4096];
+		let size = server.recv(&mut buffer).unwrap();
+
+		let mut expected = b"MESSAGE=hello\n\
+			PRIORITY=4\n\
+			SYSLOG_IDENTIFIER=app\n\
+			TARGET=app::db\n\
+			THREAD=main\n\
+			CODE_FILE=src/db.rs\n\
+			CODE_LINE=42\n\
+			FIELD_PRIORITY=high\n\
+			USER\n"
+			.to_vec();
+		expected.extend_from_slice(&3u64.to_le_bytes());
+		expected.extend_from_slice(b"a\nb\n");
+
+		assert_eq!(&buffer[..size], expected.as_slice());
+
+		_ = std::fs::remove_dir_all(dir);
+	}
+}
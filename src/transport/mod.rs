@@ -0,0 +1,49 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod gelf;
+#[cfg(unix)]
+mod journald;
+mod otlp;
+mod syslog;
+mod tcp;
+
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+
+pub use self::gelf::LoggerGelfTransport;
+#[cfg(unix)]
+pub use self::journald::LoggerJournaldTransport;
+pub use self::otlp::LoggerOtlpTransport;
+pub use self::syslog::{LoggerSyslogFacility, LoggerSyslogProtocol, LoggerSyslogTransport};
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// La première adresse correspondant à `address`.
+pub(crate) fn resolve(address: impl ToSocketAddrs) -> io::Result<SocketAddr>
+{
+	address.to_socket_addrs()?.next().ok_or_else(|| {
+		io::Error::new(io::ErrorKind::AddrNotAvailable, "Aucune adresse ne correspond")
+	})
+}
+
+/// Une socket UDP locale, de la même famille d'adresses que `address`.
+pub(crate) fn bind_udp(address: SocketAddr) -> io::Result<UdpSocket>
+{
+	let local: SocketAddr = if address.is_ipv4() {
+		([0, 0, 0, 0], 0).into()
+	} else {
+		([0u16; 8], 0).into()
+	};
+
+	UdpSocket::bind(local)
+}
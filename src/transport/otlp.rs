@@ -0,0 +1,438 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::resolve;
+use crate::entry::LogEntry;
+use crate::sink::LoggerSink;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs OTLP/JSON vers un collecteur OpenTelemetry, en
+/// HTTP.
+///
+/// Chaque log est envoyé par une requête `POST` : le texte des logs doit
+/// donc être au format OTLP/JSON (voir [crate::LoggerOtlpFormat] et
+/// [crate::Logger::otlp()]). La connexion est conservée entre deux requêtes,
+/// tant que le collecteur ne la ferme pas. Le mode asynchrone est conseillé,
+/// pour ne pas attendre la réponse du collecteur à chaque log.
+///
+/// NOTE: seul HTTP est supporté, pas HTTPS.
+pub struct LoggerOtlpTransport
+{
+	address: SocketAddr,
+	host: String,
+	path: String,
+	headers: Vec<(String, String)>,
+	connection: Mutex<Option<TcpStream>>,
+}
+
+/// Réponse du collecteur à une requête.
+struct Response
+{
+	status: u16,
+	/// La connexion, lorsqu'elle peut servir à la requête suivante.
+	keep_alive: Option<TcpStream>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerOtlpTransport
+{
+	/// Délai maximal de connexion, d'écriture et de lecture.
+	const TIMEOUT: Duration = Duration::from_secs(5);
+
+	/// Envoie les logs à l'URL, par exemple `http://localhost:4318/v1/logs`.
+	/// Sans chemin, le chemin par défaut `/v1/logs` est utilisé.
+	pub fn http(url: impl AsRef<str>) -> io::Result<Self>
+	{
+		let invalid = |reason| io::Error::new(io::ErrorKind::InvalidInput, reason);
+
+		let url = url.as_ref();
+		let Some(rest) = url.strip_prefix("http://") else {
+			return Err(invalid("Seules les URL `http://` sont supportées"));
+		};
+
+		let (host, path) = match rest.find('/') {
+			| Some(idx) => rest.split_at(idx),
+			| None => (rest, ""),
+		};
+		if host.is_empty() {
+			return Err(invalid("L'URL n'a pas d'hôte"));
+		}
+
+		let with_port = host
+			.rsplit_once(':')
+			.is_some_and(|(_, port)| port.parse::<u16>().is_ok());
+		let address = if with_port {
+			resolve(host)?
+		} else {
+			resolve((host.trim_start_matches('[').trim_end_matches(']'), 80))?
+		};
+
+		Ok(Self {
+			address,
+			host: host.to_owned(),
+			path: if path.is_empty() || path == "/" { "/v1/logs" } else { path }.to_owned(),
+			headers: Default::default(),
+			connection: Default::default(),
+		})
+	}
+
+	/// Ajoute un en-tête aux requêtes, par exemple une clé d'API.
+	pub fn with_header(mut self, name: impl ToString, value: impl ToString) -> Self
+	{
+		self.headers.push((name.to_string(), value.to_string()));
+		self
+	}
+
+	/// L'adresse du collecteur.
+	pub fn address(&self) -> SocketAddr
+	{
+		self.address
+	}
+}
+
+impl LoggerOtlpTransport
+{
+	fn post(&self, body: &[u8]) -> io::Result<()>
+	{
+		let mut request = format!(
+			"POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+			self.path,
+			self.host,
+			body.len()
+		);
+		for (name, value) in self.headers.iter() {
+			request.push_str(&format!("{name}: {value}\r\n"));
+		}
+		request.push_str("\r\n");
+
+		let mut request = request.into_bytes();
+		request.extend_from_slice(body);
+
+		let mut connection = self.connection.lock().expect("otlp connection guard");
+
+		// NOTE: le collecteur a pu fermer la connexion conservée depuis la
+		// requête précédente: la requête est alors envoyée sur une nouvelle
+		// connexion. Une requête entièrement envoyée n'est jamais renvoyée,
+		// le collecteur ayant pu l'accepter.
+		let stream = match connection.take().filter(is_open) {
+			| Some(stream) if send(&stream, &request).is_ok() => stream,
+			| _ => {
+				let stream = self.connect()?;
+				send(&stream, &request)?;
+				stream
+			}
+		};
+
+		let response = receive(stream)?;
+		*connection = response.keep_alive;
+
+		match response.status {
+			| 200..=299 => Ok(()),
+			| code => Err(io::Error::other(format!("Le collecteur a répondu {code}"))),
+		}
+	}
+
+	fn connect(&self) -> io::Result<TcpStream>
+	{
+		let stream = TcpStream::connect_timeout(&self.address, Self::TIMEOUT)?;
+		stream.set_write_timeout(Some(Self::TIMEOUT))?;
+		stream.set_read_timeout(Some(Self::TIMEOUT))?;
+		stream.set_nodelay(true)?;
+		Ok(stream)
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerSink for LoggerOtlpTransport
+{
+	fn write(&self, _: &LogEntry, text: &str) -> io::Result<()>
+	{
+		self.post(text.trim_end().as_bytes())
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Est-ce que la connexion conservée est encore ouverte, sans données
+/// inattendues en attente?
+fn is_open(stream: &TcpStream) -> bool
+{
+	if stream.set_nonblocking(true).is_err() {
+		return false;
+	}
+
+	let open = matches!(stream.peek(&mut [0]), Err(err) if err.kind() == io::ErrorKind::WouldBlock);
+	stream.set_nonblocking(false).is_ok() && open
+}
+
+/// Envoie la requête.
+fn send(mut stream: &TcpStream, request: &[u8]) -> io::Result<()>
+{
+	stream.write_all(request)?;
+	stream.flush()
+}
+
+/// Lit entièrement la réponse, afin que la connexion puisse servir à la
+/// requête suivante.
+fn receive(stream: TcpStream) -> io::Result<Response>
+{
+	let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Réponse HTTP invalide");
+
+	let mut reader = BufReader::new(&stream);
+
+	let status_line = read_line(&mut reader)?;
+	let mut parts = status_line.split_whitespace();
+	let version = parts.next().ok_or_else(invalid)?;
+	let status = parts.next().and_then(|code| code.parse::<u16>().ok()).ok_or_else(invalid)?;
+
+	let mut close = version == "HTTP/1.0";
+	let mut content_length = None;
+	let mut chunked = false;
+
+	loop {
+		let line = read_line(&mut reader)?;
+		if line.is_empty() {
+			break;
+		}
+
+		let Some((name, value)) = line.split_once(':') else {
+			return Err(invalid());
+		};
+		let value = value.trim();
+
+		match name.trim().to_ascii_lowercase().as_str() {
+			| "content-length" => content_length = Some(value.parse::<u64>().map_err(|_| invalid())?),
+			| "transfer-encoding" => chunked = value.to_ascii_lowercase().contains("chunked"),
+			| "connection" if value.eq_ignore_ascii_case("close") => close = true,
+			| "connection" if value.eq_ignore_ascii_case("keep-alive") => close = false,
+			| _ => {}
+		}
+	}
+
+	if matches!(status, 100..=199 | 204 | 304) {
+		// NOTE: pas de corps.
+	} else if chunked {
+		loop {
+			let line = read_line(&mut reader)?;
+			let size = line.split(';').next().unwrap_or_default().trim();
+			let size = u64::from_str_radix(size, 16).map_err(|_| invalid())?;
+			if size == 0 {
+				while !read_line(&mut reader)?.is_empty() {}
+				break;
+			}
+			discard(&mut reader, size)?;
+			read_line(&mut reader)?;
+		}
+	} else if let Some(length) = content_length {
+		discard(&mut reader, length)?;
+	} else {
+		// NOTE: sans longueur, le corps s'arrête à la fin de la connexion.
+		io::copy(&mut reader, &mut io::sink())?;
+		close = true;
+	}
+
+	drop(reader);
+
+	Ok(Response {
+		status,
+		keep_alive: (!close).then_some(stream),
+	})
+}
+
+/// Lit une ligne de la réponse, sans son retour à la ligne.
+fn read_line(reader: &mut impl BufRead) -> io::Result<String>
+{
+	let mut line = String::new();
+	if reader.read_line(&mut line)? == 0 {
+		return Err(io::ErrorKind::UnexpectedEof.into());
+	}
+	Ok(line.trim_end_matches(['\r', '\n']).to_owned())
+}
+
+/// Lit et ignore `length` octets de la réponse.
+fn discard(reader: &mut impl Read, length: u64) -> io::Result<()>
+{
+	if io::copy(&mut reader.take(length), &mut io::sink())? != length {
+		return Err(io::ErrorKind::UnexpectedEof.into());
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io::{BufRead, BufReader, Read, Write};
+	use std::net::TcpListener;
+	use std::sync::mpsc;
+	use std::thread;
+
+	use super::LoggerOtlpTransport;
+	use crate::{LogEntry, LoggerSink};
+
+	/// Collecteur de test: répond aux requêtes avec les réponses données, dans
+	/// l'ordre, et renvoie pour chaque requête le numéro de sa connexion et son
+	/// corps. Une réponse vide ferme la connexion sans répondre ; l'en-tête
+	/// `X-Test-Close` la ferme après la réponse, sans que le client le sache.
+	fn collector(responses: Vec<&'static str>) -> (LoggerOtlpTransport, mpsc::Receiver<(usize, String)>)
+	{
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = format!("http://{}/v1/logs", listener.local_addr().unwrap());
+		let (sender, receiver) = mpsc::channel();
+
+		thread::spawn(move || {
+			let mut responses = responses.into_iter();
+			for (connection, stream) in listener.incoming().enumerate() {
+				let stream = stream.unwrap();
+				let mut reader = BufReader::new(stream.try_clone().unwrap());
+				let mut writer = stream;
+
+				loop {
+					let mut length = 0;
+					let mut line = String::new();
+					if reader.read_line(&mut line).unwrap_or(0) == 0 {
+						break;
+					}
+					loop {
+						line.clear();
+						reader.read_line(&mut line).unwrap();
+						let header = line.trim_end();
+						if header.is_empty() {
+							break;
+						}
+						if let Some(value) = header.strip_prefix("Content-Length: ") {
+							length = value.parse().unwrap();
+						}
+					}
+
+					let mut body = vec![0; length];
+					reader.read_exact(&mut body).unwrap();
+					sender.send((connection, String::from_utf8(body).unwrap())).unwrap();
+
+					let Some(response) = responses.next().filter(|response| !response.is_empty()) else {
+						break;
+					};
+					writer.write_all(response.as_bytes()).unwrap();
+					if response.contains("Connection: close") || response.contains("X-Test-Close") {
+						break;
+					}
+				}
+			}
+		});
+
+		(LoggerOtlpTransport::http(url).unwrap(), receiver)
+	}
+
+	fn entry() -> LogEntry
+	{
+		LogEntry::new(log::Level::Info, "test", "message")
+	}
+
+	#[test]
+	fn requests_reuse_the_connection()
+	{
+		let (transport, requests) = collector(vec![
+			"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}",
+			"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\n{}\r\n0\r\n\r\n",
+			"HTTP/1.1 204 No Content\r\n\r\n",
+		]);
+
+		for text in ["1", "2", "3"] {
+			transport.write(&entry(), text).unwrap();
+		}
+
+		let requests: Vec<_> = requests.try_iter().collect();
+		assert_eq!(requests, vec![
+			(0, "1".to_owned()),
+			(0, "2".to_owned()),
+			(0, "3".to_owned()),
+		]);
+	}
+
+	#[test]
+	fn closed_connection_is_reopened()
+	{
+		let (transport, requests) = collector(vec![
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n",
+		]);
+
+		transport.write(&entry(), "1").unwrap();
+		transport.write(&entry(), "2").unwrap();
+
+		let requests: Vec<_> = requests.try_iter().collect();
+		assert_eq!(requests, vec![(0, "1".to_owned()), (1, "2".to_owned())]);
+	}
+
+	#[test]
+	fn error_status_is_an_error()
+	{
+		let (transport, _requests) = collector(vec![
+			"HTTP/1.1 500 Internal Server Error\r\nContent-Length: 4\r\n\r\noops",
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n",
+		]);
+
+		assert!(transport.write(&entry(), "1").is_err());
+		assert!(transport.write(&entry(), "2").is_ok());
+	}
+
+	#[test]
+	fn idle_connection_closed_by_the_collector_is_reopened()
+	{
+		let (transport, requests) = collector(vec![
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nX-Test-Close: 1\r\n\r\n",
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n",
+		]);
+
+		transport.write(&entry(), "1").unwrap();
+		std::thread::sleep(std::time::Duration::from_millis(50));
+		transport.write(&entry(), "2").unwrap();
+
+		let requests: Vec<_> = requests.try_iter().collect();
+		assert_eq!(requests, vec![(0, "1".to_owned()), (1, "2".to_owned())]);
+	}
+
+	#[test]
+	fn sent_request_is_not_sent_again()
+	{
+		let (transport, requests) = collector(vec![
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n",
+			"",
+			"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n",
+		]);
+
+		transport.write(&entry(), "1").unwrap();
+		// NOTE: le collecteur ferme la connexion sans répondre.
+		assert!(transport.write(&entry(), "2").is_err());
+		transport.write(&entry(), "3").unwrap();
+
+		let requests: Vec<_> = requests.try_iter().collect();
+		assert_eq!(requests, vec![
+			(0, "1".to_owned()),
+			(0, "2".to_owned()),
+			(1, "3".to_owned()),
+		]);
+	}
+}
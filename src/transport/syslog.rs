@@ -0,0 +1,557 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::fmt::Write;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+use super::tcp::TcpConnection;
+use super::{bind_udp, resolve};
+use crate::entry::LogEntry;
+use crate::format::{hostname, syslog_severity};
+use crate::sink::LoggerSink;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs vers un démon syslog (rsyslog, syslog-ng, ...), par
+/// une socket Unix de type datagramme, en UDP ou en TCP.
+///
+/// L'en-tête syslog (priorité, date, hôte, application, ...) est construit
+/// à partir du log ; le texte du log, sans retour à la ligne final, en est le
+/// message (voir [crate::Logger::syslog()]). La sévérité syslog est déduite
+/// du niveau du log.
+pub struct LoggerSyslogTransport
+{
+	destination: Destination,
+	protocol: LoggerSyslogProtocol,
+	facility: LoggerSyslogFacility,
+	hostname: String,
+	app_name: String,
+	procid: String,
+	structured_data: Vec<(String, Vec<(String, String)>)>,
+	key_values: Option<String>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Format des messages syslog.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum LoggerSyslogProtocol
+{
+	/// RFC 5424. En TCP, les messages sont préfixés par leur taille
+	/// (RFC 6587).
+	#[default]
+	Rfc5424,
+	/// RFC 3164, le format BSD historique, attendu par exemple sur `/dev/log`
+	/// par rsyslog. En TCP, les messages sont terminés par un retour à la
+	/// ligne : les caractères de contrôle du message (dont `\n` et `\r`)
+	/// sont donc remplacés par leur code octal, comme le fait rsyslog
+	/// (`#012` pour `\n`).
+	Rfc3164,
+}
+
+/// Facilité syslog des messages.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum LoggerSyslogFacility
+{
+	Kern = 0,
+	#[default]
+	User = 1,
+	Mail = 2,
+	Daemon = 3,
+	Auth = 4,
+	Syslog = 5,
+	Lpr = 6,
+	News = 7,
+	Uucp = 8,
+	Cron = 9,
+	Authpriv = 10,
+	Ftp = 11,
+	Local0 = 16,
+	Local1 = 17,
+	Local2 = 18,
+	Local3 = 19,
+	Local4 = 20,
+	Local5 = 21,
+	Local6 = 22,
+	Local7 = 23,
+}
+
+enum Destination
+{
+	#[cfg(unix)]
+	Unix(UnixDatagram, PathBuf),
+	Udp(UdpSocket, SocketAddr),
+	Tcp(TcpConnection),
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerSyslogTransport
+{
+	/// Envoie les logs au démon syslog local, par la socket `/dev/log`, au
+	/// format [LoggerSyslogProtocol::Rfc3164] qu'attendent les démons syslog
+	/// sur cette socket.
+	#[cfg(unix)]
+	pub fn local() -> io::Result<Self>
+	{
+		Ok(Self::unix("/dev/log")?.with_protocol(LoggerSyslogProtocol::Rfc3164))
+	}
+
+	/// Envoie les logs par une socket Unix de type datagramme.
+	#[cfg(unix)]
+	pub fn unix(path: impl AsRef<Path>) -> io::Result<Self>
+	{
+		let socket = UnixDatagram::unbound()?;
+		Ok(Self::new(Destination::Unix(socket, path.as_ref().to_owned())))
+	}
+
+	/// Envoie les logs en UDP.
+	pub fn udp(address: impl ToSocketAddrs) -> io::Result<Self>
+	{
+		let address = resolve(address)?;
+		Ok(Self::new(Destination::Udp(bind_udp(address)?, address)))
+	}
+
+	/// Envoie les logs en TCP. La connexion est établie dès maintenant.
+	///
+	/// Une connexion perdue est rétablie au log suivant. Après un échec de
+	/// connexion, les logs des 5 secondes suivantes sont écartés sans
+	/// nouvelle tentative.
+	pub fn tcp(address: impl ToSocketAddrs) -> io::Result<Self>
+	{
+		let address = resolve(address)?;
+		Ok(Self::new(Destination::Tcp(TcpConnection::connect(address)?)))
+	}
+
+	fn new(destination: Destination) -> Self
+	{
+		let app_name = std::env::current_exe()
+			.ok()
+			.and_then(|path| path.file_stem().map(|name| name.to_string_lossy().into_owned()))
+			.unwrap_or_else(|| String::from(env!("CARGO_PKG_NAME")));
+
+		Self {
+			destination,
+			protocol: Default::default(),
+			facility: Default::default(),
+			hostname: hostname(),
+			app_name,
+			procid: std::process::id().to_string(),
+			structured_data: Default::default(),
+			key_values: Default::default(),
+		}
+	}
+}
+
+impl LoggerSyslogTransport
+{
+	/// Format des messages. Par défaut, [LoggerSyslogProtocol::Rfc5424], ou
+	/// [LoggerSyslogProtocol::Rfc3164] pour [LoggerSyslogTransport::local()].
+	pub fn with_protocol(mut self, protocol: LoggerSyslogProtocol) -> Self
+	{
+		self.protocol = protocol;
+		self
+	}
+
+	/// Facilité des messages. Par défaut, [LoggerSyslogFacility::User].
+	pub fn with_facility(mut self, facility: LoggerSyslogFacility) -> Self
+	{
+		self.facility = facility;
+		self
+	}
+
+	/// Le nom de la machine. Par défaut, le nom d'hôte du système.
+	pub fn with_hostname(mut self, hostname: impl ToString) -> Self
+	{
+		self.hostname = hostname.to_string();
+		self
+	}
+
+	/// Le nom de l'application (le `TAG` de la RFC 3164). Par défaut, le nom
+	/// de l'exécutable.
+	pub fn with_app_name(mut self, app_name: impl ToString) -> Self
+	{
+		self.app_name = app_name.to_string();
+		self
+	}
+
+	/// L'identifiant du processus. Par défaut, le PID.
+	pub fn with_procid(mut self, procid: impl ToString) -> Self
+	{
+		self.procid = procid.to_string();
+		self
+	}
+
+	/// Ajoute un élément de données structurées (RFC 5424 seulement), envoyé
+	/// avec chaque message, par exemple `origin` ou `app@32473`.
+	pub fn with_structured_data<K, V>(mut self, id: impl ToString, params: impl IntoIterator<Item = (K, V)>) -> Self
+	where
+		K: ToString,
+		V: ToString,
+	{
+		let params = params
+			.into_iter()
+			.map(|(name, value)| (name.to_string(), value.to_string()))
+			.collect();
+		self.structured_data.push((id.to_string(), params));
+		self
+	}
+
+	/// Envoie les paires clé-valeur des logs dans un élément de données
+	/// structurées (RFC 5424 seulement) dont l'identifiant est `id`, par
+	/// exemple `kv@32473`.
+	pub fn with_key_values(mut self, id: impl ToString) -> Self
+	{
+		self.key_values.replace(id.to_string());
+		self
+	}
+}
+
+impl LoggerSyslogTransport
+{
+	/// Construit le message syslog.
+	fn message(&self, entry: &LogEntry, text: &str) -> String
+	{
+		let priority = (self.facility as u8) * 8 + syslog_severity(entry.level);
+		let text = text.trim_end_matches(['\r', '\n']);
+
+		match self.protocol {
+			| LoggerSyslogProtocol::Rfc5424 => {
+				format!(
+					"<{priority}>1 {} {} {} {} - {} {text}",
+					entry
+						.timestamp
+						.to_rfc3339_opts(chrono::SecondsFormat::Micros, false),
+					header_field(&self.hostname, 255),
+					header_field(&self.app_name, 48),
+					header_field(&self.procid, 128),
+					self.structured_data(entry),
+				)
+			}
+			| LoggerSyslogProtocol::Rfc3164 => {
+				let tag: String = self
+					.app_name
+					.chars()
+					.filter(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.'))
+					.take(32)
+					.collect();
+
+				let mut message = format!(
+					"<{priority}>{} {} {tag}[{}]: ",
+					entry.timestamp.format("%b %e %H:%M:%S"),
+					header_field(&self.hostname, 255),
+					self.procid,
+				);
+				for ch in text.chars() {
+					if ch.is_ascii_control() {
+						_ = write!(message, "#{:03o}", ch as u32);
+					} else {
+						message.push(ch);
+					}
+				}
+				message
+			}
+		}
+	}
+
+	/// Les données structurées du message, ou `-` lorsqu'il n'y en a pas.
+	fn structured_data(&self, entry: &LogEntry) -> String
+	{
+		let key_values = self
+			.key_values
+			.as_ref()
+			.filter(|_| !entry.key_values.is_empty())
+			.map(|id| (id, &entry.key_values));
+
+		let elements = self
+			.structured_data
+			.iter()
+			.map(|(id, params)| (id, params))
+			.chain(key_values);
+
+		let mut output = String::new();
+
+		for (id, params) in elements {
+			output.push('[');
+			output.push_str(&sd_name(id));
+			for (name, value) in params.iter() {
+				output.push(' ');
+				output.push_str(&sd_name(name));
+				output.push_str("=\"");
+				for ch in value.chars() {
+					if matches!(ch, '"' | '\\' | ']') {
+						output.push('\\');
+					}
+					output.push(ch);
+				}
+				output.push('"');
+			}
+			output.push(']');
+		}
+
+		if output.is_empty() {
+			output.push('-');
+		}
+
+		output
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerSink for LoggerSyslogTransport
+{
+	fn write(&self, entry: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let message = self.message(entry, text);
+
+		match &self.destination {
+			#[cfg(unix)]
+			| Destination::Unix(socket, path) => socket.send_to(message.as_bytes(), path).map(|_| ()),
+			| Destination::Udp(socket, address) => socket.send_to(message.as_bytes(), address).map(|_| ()),
+			| Destination::Tcp(connection) => {
+				let frame = match self.protocol {
+					| LoggerSyslogProtocol::Rfc5424 => format!("{} {message}", message.len()),
+					| LoggerSyslogProtocol::Rfc3164 => format!("{message}\n"),
+				};
+				connection.send(frame.as_bytes())
+			}
+		}
+	}
+
+	fn flush(&self) -> io::Result<()>
+	{
+		match &self.destination {
+			| Destination::Tcp(connection) => connection.flush(),
+			| _ => Ok(()),
+		}
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Un champ de l'en-tête : des caractères ASCII imprimables, sans espace,
+/// ou `-` lorsqu'il est vide.
+fn header_field(value: &str, max_len: usize) -> String
+{
+	let field: String = value
+		.chars()
+		.filter(|ch| ch.is_ascii_graphic())
+		.take(max_len)
+		.collect();
+
+	if field.is_empty() {
+		String::from("-")
+	} else {
+		field
+	}
+}
+
+/// Le nom d'un élément ou d'un paramètre de données structurées : au plus
+/// 32 caractères ASCII imprimables, sauf `=`, `]` et `"`.
+fn sd_name(name: &str) -> String
+{
+	let name: String = name
+		.chars()
+		.filter(|ch| ch.is_ascii_graphic() && !matches!(ch, '=' | ']' | '"'))
+		.take(32)
+		.collect();
+
+	if name.is_empty() {
+		String::from("_")
+	} else {
+		name
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io::{BufRead, BufReader, Read};
+	use std::net::{TcpListener, UdpSocket};
+	#[cfg(unix)]
+	use std::os::unix::net::UnixDatagram;
+	use std::time::Duration;
+
+	use chrono::TimeZone;
+
+	use super::{LoggerSyslogFacility, LoggerSyslogProtocol, LoggerSyslogTransport};
+	use crate::{LogEntry, LoggerSink};
+
+	fn entry(level: log::Level, message: &str) -> LogEntry
+	{
+		let mut entry = LogEntry::new(level, "app", message);
+		entry.timestamp = chrono::Local.with_ymd_and_hms(2026, 10, 18, 14, 5, 30).unwrap();
+		entry
+	}
+
+	fn transport(transport: LoggerSyslogTransport) -> LoggerSyslogTransport
+	{
+		transport.with_hostname("host").with_app_name("app").with_procid("42")
+	}
+
+	/// Le PRI d'un message, entre `<` et `>`.
+	fn priority(message: &str) -> u8
+	{
+		message[1..message.find('>').unwrap()].parse().unwrap()
+	}
+
+	#[test]
+	fn priority_from_facility_and_level()
+	{
+		let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+		let syslog = |facility| transport(LoggerSyslogTransport::udp(socket.local_addr().unwrap()).unwrap()).with_facility(facility);
+
+		let kern = syslog(LoggerSyslogFacility::Kern);
+		let severities: Vec<_> = log::Level::iter()
+			.map(|level| priority(&kern.message(&entry(level, "hello"), "hello\n")))
+			.collect();
+		assert_eq!(severities, [3, 4, 6, 7, 7]);
+
+		let message = |facility, level| priority(&syslog(facility).message(&entry(level, "hello"), "hello\n"));
+		assert_eq!(message(LoggerSyslogFacility::User, log::Level::Info), 14);
+		assert_eq!(message(LoggerSyslogFacility::Local0, log::Level::Error), 131);
+		assert_eq!(message(LoggerSyslogFacility::Local7, log::Level::Debug), 191);
+	}
+
+	#[test]
+	fn rfc5424_over_udp()
+	{
+		let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+		server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+		let syslog = transport(LoggerSyslogTransport::udp(server.local_addr().unwrap()).unwrap())
+			.with_structured_data("origin", [("ip", "10.0.0.1")])
+			.with_key_values("kv@32473");
+
+		let mut entry = entry(log::Level::Warn, "hello");
+		entry.key_values.push(("query".to_owned(), r#"a]b"c\d"#.to_owned()));
+		syslog.write(&entry, "hello\n").unwrap();
+
+		let mut buffer = [0; 2048];
+		let size = server.recv(&mut buffer).unwrap();
+		let message = std::str::from_utf8(&buffer[..size]).unwrap();
+
+		let (header, rest) = message.split_once(" [").unwrap();
+		let fields: Vec<_> = header.split(' ').collect();
+		assert_eq!(fields[0], "<12>1");
+		assert_eq!(
+			chrono::DateTime::parse_from_rfc3339(fields[1]).unwrap(),
+			entry.timestamp
+		);
+		assert_eq!(fields[2..], ["host", "app", "42", "-"]);
+		assert_eq!(rest, r#"origin ip="10.0.0.1"][kv@32473 query="a\]b\"c\\d"] hello"#);
+	}
+
+	#[test]
+	fn rfc5424_without_structured_data()
+	{
+		let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+		let syslog = transport(LoggerSyslogTransport::udp(server.local_addr().unwrap()).unwrap()).with_key_values("kv");
+
+		let message = syslog.message(&entry(log::Level::Info, "hello"), "hello\n");
+		assert!(message.ends_with(" host app 42 - - hello"), "{message}");
+	}
+
+	#[test]
+	fn rfc3164_over_tcp_is_one_line_per_log()
+	{
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let syslog = transport(LoggerSyslogTransport::tcp(listener.local_addr().unwrap()).unwrap())
+			.with_protocol(LoggerSyslogProtocol::Rfc3164);
+		let (stream, _) = listener.accept().unwrap();
+		stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+		let text = "hello\n<11>Oct 18 14:05:30 host forged[1]: injected\r\n";
+		syslog.write(&entry(log::Level::Info, text), text).unwrap();
+		syslog.write(&entry(log::Level::Error, "bye"), "bye\n").unwrap();
+		drop(syslog);
+
+		let lines: Vec<_> = BufReader::new(stream).lines().map(Result::unwrap).collect();
+		assert_eq!(lines, [
+			"<14>Oct 18 14:05:30 host app[42]: hello#012<11>Oct 18 14:05:30 host forged[1]: injected",
+			"<11>Oct 18 14:05:30 host app[42]: bye",
+		]);
+	}
+
+	#[test]
+	fn rfc5424_over_tcp_is_octet_counted()
+	{
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let syslog = transport(LoggerSyslogTransport::tcp(listener.local_addr().unwrap()).unwrap());
+		let (mut stream, _) = listener.accept().unwrap();
+		stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+		let entry = entry(log::Level::Info, "a\nb");
+		syslog.write(&entry, "a\nb\n").unwrap();
+		drop(syslog);
+
+		let mut received = String::new();
+		stream.read_to_string(&mut received).unwrap();
+
+		let (size, message) = received.split_once(' ').unwrap();
+		assert_eq!(size.parse::<usize>().unwrap(), message.len());
+		assert!(message.starts_with("<14>1 ") && message.ends_with(" host app 42 - - a\nb"), "{message:?}");
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn rfc3164_over_unix_datagram()
+	{
+		let dir = crate::file::temp_dir("syslog");
+		let path = dir.join("log");
+		let server = UnixDatagram::bind(&path).unwrap();
+		server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+
+		let syslog = transport(LoggerSyslogTransport::unix(&path).unwrap())
+			.with_protocol(LoggerSyslogProtocol::Rfc3164)
+			.with_facility(LoggerSyslogFacility::Daemon)
+			.with_app_name("my app!");
+		syslog.write(&entry(log::Level::Warn, "hello"), "hello\n").unwrap();
+
+		let mut buffer = [0; 2048];
+		let size = server.recv(&mut buffer).unwrap();
+		assert_eq!(&buffer[..size], b"<28>Oct 18 14:05:30 host myapp[42]: hello");
+
+		_ = std::fs::remove_dir_all(dir);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn local_socket_uses_rfc3164()
+	{
+		assert_eq!(LoggerSyslogTransport::local().unwrap().protocol, LoggerSyslogProtocol::Rfc3164);
+		assert_eq!(
+			LoggerSyslogTransport::unix("/dev/log").unwrap().protocol,
+			LoggerSyslogProtocol::Rfc5424
+		);
+	}
+}
@@ -0,0 +1,143 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Connexion TCP vers un serveur de logs, rétablie au message suivant
+/// lorsqu'elle est perdue.
+///
+/// Après un échec de connexion, aucune nouvelle tentative n'est faite
+/// pendant quelques secondes : les messages sont refusés immédiatement, au
+/// lieu de bloquer chaque log jusqu'à l'expiration du délai de connexion
+/// tant que le serveur est indisponible.
+pub(crate) struct TcpConnection
+{
+	address: SocketAddr,
+	state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State
+{
+	stream: Option<TcpStream>,
+	/// Date à partir de laquelle une nouvelle connexion peut être tentée.
+	retry_at: Option<Instant>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl TcpConnection
+{
+	/// Délai maximal de connexion et d'écriture.
+	const TIMEOUT: Duration = Duration::from_secs(5);
+
+	/// Délai entre deux tentatives de connexion.
+	const BACKOFF: Duration = Duration::from_secs(5);
+
+	/// Établit la connexion dès maintenant.
+	pub(crate) fn connect(address: SocketAddr) -> io::Result<Self>
+	{
+		Ok(Self {
+			address,
+			state: Mutex::new(State {
+				stream: Some(Self::open(address)?),
+				retry_at: None,
+			}),
+		})
+	}
+
+	fn open(address: SocketAddr) -> io::Result<TcpStream>
+	{
+		let stream = TcpStream::connect_timeout(&address, Self::TIMEOUT)?;
+		stream.set_write_timeout(Some(Self::TIMEOUT))?;
+		stream.set_nodelay(true)?;
+		Ok(stream)
+	}
+
+	/// Envoie un message déjà délimité. En cas d'échec, le message est
+	/// renvoyé une fois sur une nouvelle connexion.
+	pub(crate) fn send(&self, frame: &[u8]) -> io::Result<()>
+	{
+		let mut state = self.state.lock().expect("tcp stream guard");
+
+		if let Some(stream) = state.stream.as_mut() {
+			if stream.write_all(frame).is_ok() {
+				return Ok(());
+			}
+			state.stream.take();
+		}
+
+		if state.retry_at.is_some_and(|retry_at| Instant::now() < retry_at) {
+			return Err(io::Error::new(
+				io::ErrorKind::NotConnected,
+				"Le serveur de logs est indisponible",
+			));
+		}
+
+		let mut stream = Self::open(self.address).inspect_err(|_| {
+			state.retry_at.replace(Instant::now() + Self::BACKOFF);
+		})?;
+		state.retry_at.take();
+		stream.write_all(frame)?;
+		state.stream.replace(stream);
+		Ok(())
+	}
+
+	pub(crate) fn flush(&self) -> io::Result<()>
+	{
+		match self.state.lock().expect("tcp stream guard").stream.as_mut() {
+			| Some(stream) => stream.flush(),
+			| None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io::{self, Read};
+	use std::net::TcpListener;
+	use std::sync::Mutex;
+
+	use super::{State, TcpConnection};
+
+	#[test]
+	fn failed_connection_backs_off()
+	{
+		let address = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+		let connection = TcpConnection {
+			address,
+			state: Mutex::new(State::default()),
+		};
+
+		assert_ne!(connection.send(b"1").unwrap_err().kind(), io::ErrorKind::NotConnected);
+		assert_eq!(connection.send(b"2").unwrap_err().kind(), io::ErrorKind::NotConnected);
+
+		// NOTE: fin du délai d'attente.
+		let listener = TcpListener::bind(address).unwrap();
+		connection.state.lock().unwrap().retry_at.take();
+
+		connection.send(b"3").unwrap();
+
+		let (mut stream, _) = listener.accept().unwrap();
+		let mut received = [0; 1];
+		stream.read_exact(&mut received).unwrap();
+		assert_eq!(&received, b"3");
+	}
+}
@@ -0,0 +1,64 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+use std::io::Write;
+
+use super::extension::LoggerWriterBuilderExtension;
+use super::{LoggerWriterTarget, WriterSink};
+use crate::sink::LoggerSink;
+use crate::{LoggerBackend, LoggerBackendBuilder, LoggerBackendDestination, LoggerWriter};
+
+// ---- //
+// Type //
+// ---- //
+
+pub type LoggerWriterBuilder = LoggerBackendBuilder<LoggerWriterDestination>;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Destination des logs d'un [LoggerWriterBuilder]: un écrivain.
+pub struct LoggerWriterDestination
+{
+	writer: LoggerWriterTarget,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerWriterDestination
+{
+	pub(crate) fn new(writer: impl Write + Send + 'static) -> Self
+	{
+		Self {
+			writer: Box::new(writer),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LoggerBackendDestination for LoggerWriterDestination
+{
+	type Logger = LoggerWriter;
+
+	fn build(self, backend: impl FnOnce(Box<dyn LoggerSink>) -> LoggerBackend) -> Self::Logger
+	{
+		LoggerWriter {
+			backend: backend(Box::new(WriterSink::new(self.writer))),
+		}
+	}
+}
+
+impl LoggerWriterBuilderExtension for LoggerWriterBuilder {}
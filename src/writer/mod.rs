@@ -0,0 +1,187 @@
+// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃ Copyright: (c) 2023, Mike 'PhiSyX' S. (https://github.com/PhiSyX)         ┃
+// ┃ SPDX-License-Identifier: MPL-2.0                                          ┃
+// ┃ ╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌ ┃
+// ┃                                                                           ┃
+// ┃  This Source Code Form is subject to the terms of the Mozilla Public      ┃
+// ┃  License, v. 2.0. If a copy of the MPL was not distributed with this      ┃
+// ┃  file, You can obtain one at https://mozilla.org/MPL/2.0/.                ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+
+mod builder;
+mod extension;
+
+use std::io::{self, Write};
+use std::ops;
+use std::sync::Mutex;
+
+pub use self::builder::{LoggerWriterBuilder, LoggerWriterDestination};
+pub use self::extension::LoggerWriterBuilderExtension;
+use crate::echo::Echo;
+use crate::entry::LogEntry;
+use crate::sink::LoggerSink;
+use crate::LoggerBackend;
+
+// ---- //
+// Type //
+// ---- //
+
+pub type LoggerWriterTarget = Box<dyn Write + Send>;
+
+// --------- //
+// Structure //
+// --------- //
+
+pub struct LoggerWriter
+{
+	pub(crate) backend: LoggerBackend,
+}
+
+/// Destination d'un [LoggerWriter]: l'écrivain, protégé par un mutex.
+pub(crate) struct WriterSink
+{
+	writer: Mutex<LoggerWriterTarget>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LoggerWriter
+{
+	pub fn builder(writer: impl Write + Send + 'static) -> LoggerWriterBuilder
+	{
+		LoggerWriterBuilder::with_destination(LoggerWriterDestination::new(writer))
+	}
+}
+
+impl WriterSink
+{
+	pub(crate) fn new(writer: LoggerWriterTarget) -> Self
+	{
+		Self {
+			writer: Mutex::new(writer),
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl ops::Deref for LoggerWriter
+{
+	type Target = LoggerBackend;
+
+	fn deref(&self) -> &Self::Target
+	{
+		&self.backend
+	}
+}
+
+impl log::Log for LoggerWriter
+{
+	fn enabled(&self, metadata: &log::Metadata) -> bool
+	{
+		self.backend.enabled(metadata)
+	}
+
+	/// Écrit le log dans l'écrivain.
+	fn log(&self, record: &log::Record)
+	{
+		self.backend.log(record);
+	}
+
+	fn flush(&self)
+	{
+		self.backend.flush();
+	}
+}
+
+impl LoggerSink for WriterSink
+{
+	fn write(&self, _: &LogEntry, text: &str) -> io::Result<()>
+	{
+		let mut writer = self.writer.lock().expect("writer guard");
+		writer.write_all(Echo::strip_nul(text).as_bytes())
+	}
+
+	fn flush(&self) -> io::Result<()>
+	{
+		self.writer.lock().expect("writer guard").flush()
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::io::{self, Write};
+	use std::sync::{Arc, Mutex};
+
+	use log::Log;
+
+	use super::LoggerWriterBuilder;
+	use crate::{Echo, LogEntry, Logger, LoggerBuilder};
+
+	/// Écrivain partagé avec le test : un `Vec<u8>`, et le nombre d'appels à
+	/// `flush`.
+	#[derive(Clone)]
+	#[derive(Default)]
+	struct Shared
+	{
+		buffer: Arc<Mutex<Vec<u8>>>,
+		flushed: Arc<Mutex<usize>>,
+	}
+
+	impl Write for Shared
+	{
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+		{
+			self.buffer.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()>
+		{
+			*self.flushed.lock().unwrap() += 1;
+			Ok(())
+		}
+	}
+
+	fn builder(writer: Shared) -> LoggerWriterBuilder
+	{
+		let builder = Logger::writer(writer)
+			.with_color(false)
+			.with_format(|entry: &LogEntry, _: &mut Echo| format!("{} {}\n", entry.level, entry.message));
+
+		#[cfg(not(feature = "tracing"))]
+		let builder = builder.with_level(log::LevelFilter::Trace);
+		#[cfg(feature = "tracing")]
+		let builder = builder.with_level(tracing::level_filters::LevelFilter::TRACE);
+
+		builder
+	}
+
+	#[test]
+	fn logs_are_written_to_the_writer()
+	{
+		let writer = Shared::default();
+		let logger = builder(writer.clone()).build();
+
+		for (level, message) in [(log::Level::Info, "hello"), (log::Level::Error, "oops")] {
+			logger.log(
+				&log::Record::builder()
+					.args(format_args!("{message}"))
+					.level(level)
+					.target("test")
+					.build(),
+			);
+		}
+
+		let text = String::from_utf8(writer.buffer.lock().unwrap().clone()).unwrap();
+		assert_eq!(text, "INFO hello\nERROR oops\n");
+
+		assert_eq!(*writer.flushed.lock().unwrap(), 0);
+		logger.flush();
+		assert_eq!(*writer.flushed.lock().unwrap(), 1);
+	}
+}